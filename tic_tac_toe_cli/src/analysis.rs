@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Hint {
+    pub best_moves: Vec<String>,
+    pub verdict: String,
+    pub moves_to_end: Option<u32>,
+    pub exact: bool,
+}
+
+pub fn deserialize_hint(s: &str) -> Hint {
+    serde_json::from_str(s).unwrap()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveEvaluation {
+    pub board_move: String,
+    pub verdict: String,
+    pub exact: bool,
+}
+
+pub fn deserialize_analysis(s: &str) -> Vec<MoveEvaluation> {
+    serde_json::from_str(s).unwrap()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewedMove {
+    pub player: String,
+    pub board_move: String,
+    pub verdict_before: String,
+    pub verdict_after: String,
+    pub annotation: String,
+    pub better_moves: Vec<String>,
+}
+
+pub fn deserialize_review(s: &str) -> Vec<ReviewedMove> {
+    serde_json::from_str(s).unwrap()
+}
@@ -1,9 +1,24 @@
+use crate::analysis::deserialize_review;
 use crate::game_settings_rules::{deserialize_game_settings_rules, GameSettingRule};
-use crate::game_state::deserialize_game_state;
+use crate::game_state::{
+    deserialize_game_state, deserialize_notakto_game_state, deserialize_order_chaos_game_state,
+    deserialize_qubic_game_state, deserialize_sliding_game_state, deserialize_ultimate_game_state,
+    deserialize_variant_game_state,
+};
 use crate::inputs::{read_user_text, user_entered_yes};
-use crate::outputs::{print_board, print_result};
-use tic_tac_toe_lib::get_game_settings_rules;
-use tic_tac_toe_lib::Game;
+use crate::outputs::{
+    print_board, print_notakto_board, print_order_chaos_board, print_qubic_board, print_result,
+    print_review, print_sliding_board, print_ultimate_board,
+};
+use crate::players::{create_player, parse_player_kind, PlayerKind, PLAYER_KINDS};
+use std::sync::Arc;
+use tic_tac_toe_lib::{get_game_settings_rules, get_variant};
+use tic_tac_toe_lib::{
+    Game, NotaktoGame, OrderChaosGame, Player, QubicGame, SlidingGame, Tablebase, UltimateGame,
+};
+
+// Boards with up to this many free cells are solved before the game starts
+const MAX_SOLVED_CELLS: usize = 9;
 
 pub fn start_game() {
     println!("--- Tic Tac Toe CLI Application ---");
@@ -11,32 +26,204 @@ pub fn start_game() {
     let game_settings_rules = deserialize_game_settings_rules(get_game_settings_rules().as_str());
 
     let mut previous_game_settings: Option<String> = None;
+    let mut previous_player_kinds: Option<Vec<(String, PlayerKind)>> = None;
 
     loop {
-        let mut game = init_game(&game_settings_rules, &mut previous_game_settings);
+        let game_settings = init_game_settings(&game_settings_rules, &mut previous_game_settings);
+        match get_variant(game_settings.as_str()).unwrap().as_str() {
+            "Ultimate" => play_variant_game(
+                &mut UltimateGame::new(game_settings.as_str()).unwrap(),
+                |s| print_ultimate_board(&deserialize_ultimate_game_state(s)),
+                "board-cell, for example 5-3",
+            ),
+            "Qubic" => play_variant_game(
+                &mut QubicGame::new(game_settings.as_str()).unwrap(),
+                |s| print_qubic_board(&deserialize_qubic_game_state(s)),
+                "layer-row-column, for example 2-1-3",
+            ),
+            "Notakto" => play_variant_game(
+                &mut NotaktoGame::new(game_settings.as_str()).unwrap(),
+                |s| print_notakto_board(&deserialize_notakto_game_state(s)),
+                "board-cell, for example 2-5",
+            ),
+            "OrderChaos" => play_variant_game(
+                &mut OrderChaosGame::new(game_settings.as_str()).unwrap(),
+                |s| print_order_chaos_board(&deserialize_order_chaos_game_state(s)),
+                "cell followed by the mark X or O, for example 5O",
+            ),
+            "Sliding" => play_variant_game(
+                &mut SlidingGame::new(game_settings.as_str()).unwrap(),
+                |s| print_sliding_board(&deserialize_sliding_game_state(s)),
+                "cell, or as from-to once all pieces are placed, for example 1-5",
+            ),
+            _ => play_game(
+                Game::new(game_settings.as_str()).unwrap(),
+                game_settings.as_str(),
+                &mut previous_player_kinds,
+            ),
+        }
 
-        loop {
-            let state = deserialize_game_state(game.get_state().as_str());
-            print_board(&state);
+        #[allow(clippy::bool_comparison)]
+        if user_entered_yes("Play again?") == false {
+            break;
+        }
+    }
+}
+
+fn play_game(
+    mut game: Game,
+    game_settings: &str,
+    previous_player_kinds: &mut Option<Vec<(String, PlayerKind)>>,
+) {
+    let start_state = deserialize_game_state(game.get_state().as_str());
+    // hints and the computer play perfectly on small boards, other settings are searched
+    let free_cells = start_state
+        .board
+        .iter()
+        .flatten()
+        .filter(|cell| cell.parse::<u8>().is_ok())
+        .count();
+    if free_cells <= MAX_SOLVED_CELLS {
+        if let Ok(tablebase) = Tablebase::solve(game_settings) {
+            game.set_tablebase(Arc::new(tablebase)).unwrap();
+        }
+    }
 
-            if state.finished {
-                print_result(&state.result);
-                break;
+    let player_marks = start_state.players;
+    let mut players = init_players(&player_marks, previous_player_kinds);
+    // solving and choosing the players is not charged to X
+    game.start_clock();
+
+    loop {
+        let state = deserialize_game_state(game.get_state().as_str());
+        print_board(&state);
+
+        if state.finished {
+            print_result(&state.outcome);
+            if user_entered_yes("Review the game?") {
+                match game.get_review() {
+                    Ok(review) => print_review(&deserialize_review(review.as_str())),
+                    Err(e) => println!("Error: {}", e),
+                }
             }
-            play_move(&mut game, state.next_on_move.as_str());
+            break;
         }
 
-        if user_entered_yes("Play again?") == false {
+        if let Some(offered_by) = &state.draw_offer {
+            if *offered_by != state.next_on_move {
+                println!(
+                    "Player {} offers a draw, enter 'accept' or 'decline' (or just play a move)",
+                    offered_by
+                );
+            }
+        }
+
+        let (mark, player) = players
+            .iter_mut()
+            .find(|(mark, _)| *mark == state.next_on_move)
+            .unwrap();
+        if !play_move(&mut game, mark, player.as_mut()) {
+            println!("Player {} can not continue, the game is aborted", mark);
             break;
         }
+
+        // after a swap the player who moved first answers with the other mark
+        if !state.swapped && deserialize_game_state(game.get_state().as_str()).swapped {
+            let (first, second) = players.split_at_mut(1);
+            std::mem::swap(&mut first[0].1, &mut second[0].1);
+            println!("The players swapped sides");
+        }
     }
 }
 
-fn init_game(
+// Game types of variants that are not played on a single board
+trait VariantGame {
+    fn get_state(&self) -> String;
+    fn play_move(&mut self, move_str: &str) -> Result<(), String>;
+}
+
+impl VariantGame for UltimateGame {
+    fn get_state(&self) -> String {
+        UltimateGame::get_state(self)
+    }
+
+    fn play_move(&mut self, move_str: &str) -> Result<(), String> {
+        UltimateGame::play_move(self, move_str).map_err(|e| e.to_string())
+    }
+}
+
+impl VariantGame for NotaktoGame {
+    fn get_state(&self) -> String {
+        NotaktoGame::get_state(self)
+    }
+
+    fn play_move(&mut self, move_str: &str) -> Result<(), String> {
+        NotaktoGame::play_move(self, move_str).map_err(|e| e.to_string())
+    }
+}
+
+impl VariantGame for OrderChaosGame {
+    fn get_state(&self) -> String {
+        OrderChaosGame::get_state(self)
+    }
+
+    fn play_move(&mut self, move_str: &str) -> Result<(), String> {
+        OrderChaosGame::play_move(self, move_str).map_err(|e| e.to_string())
+    }
+}
+
+impl VariantGame for SlidingGame {
+    fn get_state(&self) -> String {
+        SlidingGame::get_state(self)
+    }
+
+    fn play_move(&mut self, move_str: &str) -> Result<(), String> {
+        SlidingGame::play_move(self, move_str).map_err(|e| e.to_string())
+    }
+}
+
+impl VariantGame for QubicGame {
+    fn get_state(&self) -> String {
+        QubicGame::get_state(self)
+    }
+
+    fn play_move(&mut self, move_str: &str) -> Result<(), String> {
+        QubicGame::play_move(self, move_str).map_err(|e| e.to_string())
+    }
+}
+
+// Variant games are played by two human players, the board is printed from the JSON state
+fn play_variant_game(game: &mut dyn VariantGame, print_board: fn(&str), move_format: &str) {
+    loop {
+        let state_str = game.get_state();
+        print_board(state_str.as_str());
+
+        let state = deserialize_variant_game_state(state_str.as_str());
+        if state.finished {
+            print_result(&state.outcome);
+            break;
+        }
+
+        loop {
+            let message = format!(
+                "Player {} please enter the move as {}",
+                state.next_on_move, move_format
+            );
+            match game.play_move(read_user_text(message.as_str(), true).as_str()) {
+                Ok(()) => break,
+                Err(e) => println!("Error: {}", e),
+            }
+        }
+    }
+}
+
+// Returns settings that a game of their variant can be created with
+fn init_game_settings(
     game_settings_rules: &Vec<GameSettingRule>,
     previous_game_settings: &mut Option<String>,
-) -> Game {
+) -> String {
     loop {
+        #[allow(clippy::bool_comparison)]
         let ask_for_new_game_settings =
             previous_game_settings.is_none() || user_entered_yes("Use old Game Settings?") == false;
 
@@ -44,12 +231,13 @@ fn init_game(
             println!("Please enter the game settings. Rules are:");
             for rule in game_settings_rules {
                 println!(
-                    "  {}: Allowed Values: {}. Default Value: {}",
-                    rule.name, rule.allowed_values, rule.default_value
+                    "  {} ({}): Allowed Values: {}. Default Value: {}",
+                    rule.name, rule.key, rule.allowed_values, rule.default_value
                 );
             }
-            let mut user_text = read_user_text("  Please separate values with comma, for example: 'X,3'\n  Hit Enter for default values",true);
+            let mut user_text = read_user_text("  Please separate values with comma, for example: 'X,3' or 'X,3,early_draw=On'\n  First Player and Board Size can be given by position, other settings as key=value\n  Hit Enter for default values",true);
 
+            #[allow(clippy::len_zero, clippy::cmp_owned)]
             if user_text.len() == 0 {
                 let rule_first_player = game_settings_rules
                     .iter()
@@ -64,30 +252,124 @@ fn init_game(
                     + rule_board_size.default_value.as_str();
             }
 
-            match Game::new(user_text.as_str()) {
-                Ok(game) => {
-                    *previous_game_settings = Some(String::from(user_text));
-                    return game;
+            match validate_game_settings(user_text.as_str()) {
+                Ok(()) => {
+                    *previous_game_settings = Some(user_text.clone());
+                    return user_text;
                 }
                 Err(e) => {
                     println!("Error: {}", e);
                 }
             }
         } else {
-            let s = previous_game_settings.as_ref().unwrap().as_str();
-            return Game::new(s).unwrap();
+            return previous_game_settings.clone().unwrap();
+        }
+    }
+}
+
+fn validate_game_settings(s: &str) -> Result<(), String> {
+    let result = match get_variant(s).map_err(|e| e.to_string())?.as_str() {
+        "Ultimate" => UltimateGame::new(s).map(|_| ()),
+        "Qubic" => QubicGame::new(s).map(|_| ()),
+        "Notakto" => NotaktoGame::new(s).map(|_| ()),
+        "OrderChaos" => OrderChaosGame::new(s).map(|_| ()),
+        "Sliding" => SlidingGame::new(s).map(|_| ()),
+        _ => Game::new(s).map(|_| ()),
+    };
+    result.map_err(|e| e.to_string())
+}
+
+// Old players can be kept only when the game has the same players
+fn init_players(
+    player_marks: &[String],
+    previous_player_kinds: &mut Option<Vec<(String, PlayerKind)>>,
+) -> Vec<(String, Box<dyn Player>)> {
+    let same_players = previous_player_kinds.as_ref().is_some_and(|player_kinds| {
+        player_kinds
+            .iter()
+            .map(|(mark, _)| mark)
+            .eq(player_marks.iter())
+    });
+    let ask_for_new_players = !same_players || !user_entered_yes("Use old Players?");
+
+    if ask_for_new_players {
+        let mut player_kinds: Vec<(String, PlayerKind)> = vec![];
+        for mark in player_marks {
+            player_kinds.push((mark.clone(), read_player_kind(mark)));
         }
+        *previous_player_kinds = Some(player_kinds);
     }
+
+    previous_player_kinds
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|(mark, kind)| (mark.clone(), create_player(kind)))
+        .collect()
 }
 
-fn play_move(game: &mut Game, player_mark: &str) {
+fn read_player_kind(mark: &str) -> PlayerKind {
     loop {
-        let message = String::from("Player ") + player_mark + " please enter the move";
-        let user_text = read_user_text(message.as_str(), true);
-        match game.play_move(user_text.as_str()) {
-            Ok(()) => return,
+        let message = format!(
+            "Who plays {}? Allowed Values: {}. Hit Enter for Human",
+            mark, PLAYER_KINDS
+        );
+        match parse_player_kind(read_user_text(message.as_str(), true).as_str()) {
+            Some(PlayerKind::Replay(_)) => {
+                let moves = read_user_text(
+                    "  Please enter the moves to replay, separated with comma",
+                    true,
+                );
+                return PlayerKind::Replay(
+                    moves.split(',').map(|m| String::from(m.trim())).collect(),
+                );
+            }
+            Some(kind) => return kind,
+            None => println!("Wrong player, please use one of: {}", PLAYER_KINDS),
+        }
+    }
+}
+
+// Returns false when a non-interactive player can not produce a valid move.
+// Resign, draw and swap commands are accepted too, after an offer or a decline the player still moves.
+fn play_move(game: &mut Game, player_mark: &str, player: &mut dyn Player) -> bool {
+    loop {
+        let user_text = player.choose_move(game);
+        if !player.is_interactive() {
+            println!("Player {} plays {}", player_mark, user_text);
+        }
+        let command = user_text.trim().to_lowercase();
+        let result = match command.as_str() {
+            "resign" => game.resign(player_mark),
+            "offer draw" => game.offer_draw(player_mark),
+            "accept" => game.accept_draw(player_mark),
+            "decline" => game.decline_draw(player_mark),
+            "swap" => game.swap(player_mark),
+            _ => game.play_move(user_text.as_str()),
+        };
+        match result {
+            Ok(()) if command == "offer draw" => {
+                println!(
+                    "Player {} offered a draw, please enter the move",
+                    player_mark
+                )
+            }
+            Ok(()) if command == "decline" => {
+                println!(
+                    "Player {} declined the draw, please enter the move",
+                    player_mark
+                )
+            }
+            Ok(()) => return true,
             Err(e) => {
                 println!("Error: {}", e);
+                // running out of time ends the game
+                if game.get_outcome().is_finished() {
+                    return true;
+                }
+                if !player.is_interactive() {
+                    return false;
+                }
             }
         }
     }
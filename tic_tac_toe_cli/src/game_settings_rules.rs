@@ -3,6 +3,7 @@ use serde::Deserialize;
 #[derive(Debug, Deserialize)]
 pub struct GameSettingRule {
     pub name: String,
+    pub key: String,
     pub allowed_values: String,
     pub default_value: String,
 }
@@ -5,9 +5,104 @@ pub struct GameState {
     pub board: Vec<Vec<String>>,
     pub next_on_move: String,
     pub finished: bool,
-    pub result: String,
+    pub outcome: Outcome,
+    pub draw_offer: Option<String>,
+    pub remaining_time: Vec<RemainingTime>,
+    pub playable_columns: Option<Vec<u8>>,
+    pub placeable_marks: Vec<String>,
+    pub players: Vec<String>,
+    pub eliminated: Vec<String>,
+    pub swap_allowed: bool,
+    pub swapped: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemainingTime {
+    pub mark: String,
+    pub milliseconds: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Outcome {
+    pub status: String,
+    pub winner: Option<String>,
+    pub reason: Option<String>,
+    pub line: Vec<u8>,
 }
 
 pub fn deserialize_game_state(s: &str) -> GameState {
     serde_json::from_str(s).unwrap()
 }
+
+// Fields every variant game state has
+#[derive(Debug, Deserialize)]
+pub struct VariantGameState {
+    pub next_on_move: String,
+    pub finished: bool,
+    pub outcome: Outcome,
+}
+
+pub fn deserialize_variant_game_state(s: &str) -> VariantGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UltimateGameState {
+    pub boards: Vec<Vec<Vec<String>>>,
+    pub board_outcomes: Vec<Outcome>,
+    pub active_board: Option<u8>,
+    pub finished: bool,
+}
+
+pub fn deserialize_ultimate_game_state(s: &str) -> UltimateGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QubicGameState {
+    pub layers: Vec<Vec<Vec<String>>>,
+    pub outcome: Outcome,
+}
+
+pub fn deserialize_qubic_game_state(s: &str) -> QubicGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotaktoGameState {
+    pub boards: Vec<Vec<Vec<String>>>,
+    pub dead_boards: Vec<bool>,
+}
+
+pub fn deserialize_notakto_game_state(s: &str) -> NotaktoGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OrderChaosGameState {
+    pub board: Vec<Vec<String>>,
+    pub outcome: Outcome,
+}
+
+pub fn deserialize_order_chaos_game_state(s: &str) -> OrderChaosGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SlidingGameState {
+    pub board: Vec<Vec<String>>,
+    pub phase: String,
+    pub pieces_to_place: Vec<PiecesToPlace>,
+    pub finished: bool,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PiecesToPlace {
+    pub mark: String,
+    pub count: u8,
+}
+
+pub fn deserialize_sliding_game_state(s: &str) -> SlidingGameState {
+    serde_json::from_str(s).unwrap()
+}
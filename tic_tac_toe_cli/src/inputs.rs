@@ -4,6 +4,7 @@ pub fn user_entered_yes(message: &str) -> bool {
     loop {
         let text = message.to_owned();
         let answer = read_user_text((text + " (y/N)").as_str(), true);
+        #[allow(clippy::len_zero)]
         if answer.len() == 0 {
             return false;
         }
@@ -17,6 +18,7 @@ pub fn user_entered_yes(message: &str) -> bool {
     }
 }
 
+#[allow(clippy::len_zero, clippy::chars_last_cmp)]
 pub fn read_user_text(message: &str, remove_trailing_line_feed: bool) -> String {
     println!("{}", message);
 
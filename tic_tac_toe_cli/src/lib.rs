@@ -1,5 +1,7 @@
+mod analysis;
 pub mod front_end;
 mod game_settings_rules;
 mod game_state;
 mod inputs;
 mod outputs;
+mod players;
@@ -1,15 +1,222 @@
-use crate::game_state::GameState;
-use colored::Colorize;
+use crate::analysis::{Hint, MoveEvaluation, ReviewedMove};
+use crate::game_state::{
+    GameState, NotaktoGameState, OrderChaosGameState, Outcome, QubicGameState, SlidingGameState,
+    UltimateGameState,
+};
+use colored::{ColoredString, Colorize};
 
 pub fn print_board(state: &GameState) {
-    let board_dim = state.board.len();
+    print_board_with_evaluations(state, &[]);
+}
+
+// Empty cells with an evaluation are colored by its verdict, others stay grey.
+// Cells of the winning line are highlighted, remaining time is shown right of the rows.
+// With gravity empty cells are dots and the column numbers below carry the evaluations.
+fn print_board_with_evaluations(state: &GameState, evaluations: &[MoveEvaluation]) {
+    let board_width = state.board[0].len();
+
+    println!("---");
+    for (row_idx, row) in state.board.iter().enumerate() {
+        for (col_idx, cell) in row.iter().enumerate() {
+            let cell_str = format!("{: >3}", cell);
+            if cell_str.trim().parse::<u8>().is_ok() && state.playable_columns.is_some() {
+                print!("{}", format!("{: >3}", ".").bright_black());
+            } else if cell_str.trim().parse::<u8>().is_ok() {
+                match find_evaluation(evaluations, cell) {
+                    Some(e) => print!("{}", color_by_verdict(&cell_str, &e.verdict)),
+                    None => print!("{}", cell_str.bright_black()),
+                }
+            } else if state
+                .outcome
+                .line
+                .contains(&((row_idx * board_width + col_idx + 1) as u8))
+            {
+                print!("{}", cell_str.green().bold());
+            } else {
+                print!("{}", color_by_mark(&cell_str, cell));
+            }
+        }
+        if let Some(remaining_time) = state.remaining_time.get(row_idx) {
+            print!(
+                "     {} {}",
+                remaining_time.mark,
+                format_time(remaining_time.milliseconds)
+            );
+        }
+        println!();
+    }
+
+    if !state.eliminated.is_empty() && !state.finished {
+        println!("Eliminated: {}", state.eliminated.join(", "));
+    }
+
+    if let Some(playable_columns) = &state.playable_columns {
+        for col_number in 1..=board_width as u8 {
+            let col_str = format!("{: >3}", col_number);
+            match find_evaluation(evaluations, &col_number.to_string()) {
+                Some(e) => print!("{}", color_by_verdict(&col_str, &e.verdict)),
+                None if playable_columns.contains(&col_number) => print!("{}", col_str),
+                None => print!("{}", col_str.bright_black()),
+            }
+        }
+        println!();
+    }
+}
+
+// Small boards side by side in rows of boards. Cells of the board to play on are
+// highlighted, decided boards are colored by their winner.
+pub fn print_ultimate_board(state: &UltimateGameState) {
+    let board_dim = state.board_outcomes.len().isqrt();
 
     println!("---");
+    for big_row_idx in 0..board_dim {
+        if big_row_idx > 0 {
+            println!(
+                "{}",
+                "-".repeat(board_dim * board_dim * 3 + (board_dim - 1) * 2)
+            );
+        }
+        for row_idx in 0..board_dim {
+            for big_col_idx in 0..board_dim {
+                let board_idx = big_row_idx * board_dim + big_col_idx;
+                let board_outcome = &state.board_outcomes[board_idx];
+                let is_active = !state.finished
+                    && board_outcome.status == "InProgress"
+                    && state
+                        .active_board
+                        .is_none_or(|active| active as usize == board_idx + 1);
+
+                if big_col_idx > 0 {
+                    print!(" |");
+                }
+                for cell in &state.boards[board_idx][row_idx] {
+                    let cell_str = format!("{: >3}", cell);
+                    let is_empty = cell_str.trim().parse::<u8>().is_ok();
+                    match board_outcome.winner.as_deref() {
+                        Some("X") => print!("{}", cell_str.blue()),
+                        Some(_) => print!("{}", cell_str.red()),
+                        None if is_empty && is_active => print!("{}", cell_str.yellow()),
+                        None if is_empty => print!("{}", cell_str.bright_black()),
+                        None => print!("{}", cell_str),
+                    }
+                }
+            }
+            println!();
+        }
+    }
+    match state.active_board {
+        Some(board_number) if !state.finished => println!("Board to play on: {}", board_number),
+        None if !state.finished => println!("Any open board can be played on"),
+        _ => {}
+    }
+}
+
+// Layers side by side with row and column numbers, the winning line is highlighted
+pub fn print_qubic_board(state: &QubicGameState) {
+    let cube_dim = state.layers.len();
+    let layer_width = 3 * (cube_dim + 1);
+
+    println!("---");
+    let headers: Vec<String> = (1..=cube_dim)
+        .map(|layer_number| {
+            format!(
+                "{: >w$}",
+                format!("Layer {}", layer_number),
+                w = layer_width
+            )
+        })
+        .collect();
+    println!("{}", headers.join("  "));
+    let column_numbers: String = (1..=cube_dim).map(|c| format!("{: >3}", c)).collect();
+    println!(
+        "{}",
+        vec![format!("   {}", column_numbers); cube_dim].join("  ")
+    );
+
+    for row_idx in 0..cube_dim {
+        for (layer_idx, layer) in state.layers.iter().enumerate() {
+            if layer_idx > 0 {
+                print!("  ");
+            }
+            print!("{: >3}", row_idx + 1);
+            for (col_idx, cell) in layer[row_idx].iter().enumerate() {
+                let cell_number = ((layer_idx * cube_dim + row_idx) * cube_dim + col_idx + 1) as u8;
+                let cell_str = format!("{: >3}", if cell.is_empty() { "." } else { cell });
+                if state.outcome.line.contains(&cell_number) {
+                    print!("{}", cell_str.green().bold());
+                } else if cell.is_empty() {
+                    print!("{}", cell_str.bright_black());
+                } else {
+                    print!("{}", cell_str);
+                }
+            }
+        }
+        println!();
+    }
+}
+
+// Boards side by side with their numbers above, dead boards are red
+pub fn print_notakto_board(state: &NotaktoGameState) {
+    let board_dim = state.boards[0].len();
+
+    println!("---");
+    let headers: Vec<String> = (1..=state.boards.len())
+        .map(|board_number| {
+            let header = format!(
+                "{: >w$}",
+                format!("Board {}", board_number),
+                w = 3 * board_dim
+            );
+            match state.dead_boards[board_number - 1] {
+                true => header.red().to_string(),
+                false => header,
+            }
+        })
+        .collect();
+    println!("{}", headers.join("  |"));
+
     for row_idx in 0..board_dim {
-        for col_idx in 0..board_dim {
-            let cell_str = format!("{: >3}", &state.board[row_idx][col_idx]);
+        for (board_idx, board) in state.boards.iter().enumerate() {
+            if board_idx > 0 {
+                print!("  |");
+            }
+            for cell in &board[row_idx] {
+                let cell_str = format!("{: >3}", cell);
+                let is_empty = cell_str.trim().parse::<u8>().is_ok();
+                if state.dead_boards[board_idx] {
+                    print!("{}", cell_str.red());
+                } else if is_empty {
+                    print!("{}", cell_str.bright_black());
+                } else {
+                    print!("{}", cell_str);
+                }
+            }
+        }
+        println!();
+    }
+
+    let dead_boards: Vec<String> = (1..=state.boards.len())
+        .filter(|board_number| state.dead_boards[board_number - 1])
+        .map(|board_number| board_number.to_string())
+        .collect();
+    if !dead_boards.is_empty() {
+        println!("Dead boards: {}", dead_boards.join(", "));
+    }
+}
+
+// Empty cells are grey, cells of the line of five are highlighted
+pub fn print_order_chaos_board(state: &OrderChaosGameState) {
+    let board_dim = state.board.len();
+
+    println!("---");
+    for (row_idx, row) in state.board.iter().enumerate() {
+        for (col_idx, cell) in row.iter().enumerate() {
+            let cell_str = format!("{: >3}", cell);
+            let cell_number = (row_idx * board_dim + col_idx + 1) as u8;
             if cell_str.trim().parse::<u8>().is_ok() {
                 print!("{}", cell_str.bright_black());
+            } else if state.outcome.line.contains(&cell_number) {
+                print!("{}", cell_str.green().bold());
             } else {
                 print!("{}", cell_str);
             }
@@ -18,10 +225,216 @@ pub fn print_board(state: &GameState) {
     }
 }
 
-pub fn print_result(result: &str) {
-    if result == "Tied" {
-        println!("The game is tied");
+// Pieces are colored by their mark, the pieces still to place are listed below
+pub fn print_sliding_board(state: &SlidingGameState) {
+    let board_width = state.board[0].len();
+
+    println!("---");
+    for (row_idx, row) in state.board.iter().enumerate() {
+        for (col_idx, cell) in row.iter().enumerate() {
+            let cell_str = format!("{: >3}", cell);
+            let cell_number = (row_idx * board_width + col_idx + 1) as u8;
+            if cell_str.trim().parse::<u8>().is_ok() {
+                print!("{}", cell_str.bright_black());
+            } else if state.outcome.line.contains(&cell_number) {
+                print!("{}", cell_str.green().bold());
+            } else {
+                print!("{}", color_by_mark(&cell_str, cell));
+            }
+        }
+        println!();
+    }
+
+    if state.finished {
+        return;
+    }
+    match state.phase.as_str() {
+        "Placing" => {
+            let pieces: Vec<String> = state
+                .pieces_to_place
+                .iter()
+                .map(|p| format!("{} {}", p.mark, p.count))
+                .collect();
+            println!("Pieces to place: {}", pieces.join(", "));
+        }
+        _ => println!("All pieces are placed, slide one of yours to an empty cell"),
+    }
+}
+
+// m:ss.t
+fn format_time(milliseconds: u64) -> String {
+    let seconds = milliseconds / 1000;
+    format!(
+        "{}:{:02}.{}",
+        seconds / 60,
+        seconds % 60,
+        milliseconds % 1000 / 100
+    )
+}
+
+// Best evaluation of the moves on a cell or column, in wild there is one per mark like 5O
+fn find_evaluation<'a>(
+    evaluations: &'a [MoveEvaluation],
+    position: &str,
+) -> Option<&'a MoveEvaluation> {
+    evaluations
+        .iter()
+        .filter(|e| e.board_move.trim_end_matches(char::is_alphabetic) == position)
+        .max_by_key(|e| {
+            ["Loss", "Worse", "Even", "Draw", "Better", "Win"]
+                .iter()
+                .position(|verdict| *verdict == e.verdict)
+        })
+}
+
+// Every player has a color, in a game of two players X is blue and O is red.
+// Blocked cells are shown as a dimmed '#'.
+fn color_by_mark(s: &str, mark: &str) -> ColoredString {
+    match mark {
+        "X" => s.blue(),
+        "O" => s.red(),
+        "Δ" => s.yellow(),
+        "□" => s.magenta(),
+        "#" => s.dimmed(),
+        _ => s.normal(),
+    }
+}
+
+fn color_by_verdict(s: &str, verdict: &str) -> ColoredString {
+    match verdict {
+        "Win" => s.green(),
+        "Draw" => s.yellow(),
+        "Loss" => s.red(),
+        "Better" => s.bright_green(),
+        "Even" => s.bright_yellow(),
+        "Worse" => s.bright_red(),
+        _ => s.normal(),
+    }
+}
+
+pub fn print_result(outcome: &Outcome) {
+    let winner = outcome.winner.clone().unwrap_or_default();
+    let line: Vec<String> = outcome.line.iter().map(|c| c.to_string()).collect();
+    match (outcome.status.as_str(), outcome.reason.as_deref()) {
+        ("Win", Some("LastBoard")) => {
+            println!(
+                "The winner is {}, the opponent killed the last board",
+                winner.green()
+            )
+        }
+        ("Win", Some("BoardFull")) => {
+            println!(
+                "The winner is {}, the board is full without a line",
+                winner.green()
+            )
+        }
+        ("Win", Some("Resignation")) => {
+            println!("The winner is {}, the opponent resigned", winner.green())
+        }
+        ("Win", Some("Timeout")) => {
+            println!(
+                "The winner is {}, the opponent ran out of time",
+                winner.green()
+            )
+        }
+        ("Win", Some("Elimination")) => {
+            println!(
+                "The winner is {}, all other players are eliminated",
+                winner.green()
+            )
+        }
+        ("Win", Some("Blocked")) => {
+            println!(
+                "The winner is {}, the opponent can not slide any piece",
+                winner.green()
+            )
+        }
+        ("Win", Some("Misere")) => {
+            println!(
+                "The winner is {}, the opponent completed line {} and loses by the Misere rule",
+                winner.green(),
+                line.join("-")
+            )
+        }
+        ("Win", _) => {
+            println!(
+                "The winner is {}, completed line {}",
+                winner.green(),
+                line.join("-")
+            )
+        }
+        ("Draw", Some("DeadPosition")) => {
+            println!("The game is tied, no line can be completed anymore")
+        }
+        ("Draw", Some("Repetition")) => {
+            println!("The game is tied, the same position came up three times")
+        }
+        ("Draw", Some("Agreement")) => println!("The game is tied, the players agreed to a draw"),
+        ("Draw", _) => println!("The game is tied, the board is full"),
+        _ => println!("The game is still in progress"),
+    }
+}
+
+pub fn print_hint(hint: &Hint, show_all_moves: bool) {
+    let moves = if show_all_moves {
+        hint.best_moves.join(", ")
     } else {
-        println!("The winner is {}", result.green());
+        hint.best_moves[0].clone()
+    };
+    println!("Best move: {}", moves.green());
+
+    let mut verdict = match hint.moves_to_end {
+        Some(n) => format!("{} (game ends in {} moves)", hint.verdict, n),
+        None => hint.verdict.clone(),
+    };
+    if !hint.exact {
+        verdict += " - estimate, the search is depth-limited";
+    }
+    println!("Verdict: {}", verdict);
+}
+
+pub fn print_analysis(state: &GameState, evaluations: &[MoveEvaluation]) {
+    print_board_with_evaluations(state, evaluations);
+
+    println!(
+        "Legend: {} {} {}",
+        color_by_verdict("Win", "Win"),
+        color_by_verdict("Draw", "Draw"),
+        color_by_verdict("Loss", "Loss")
+    );
+    if evaluations.iter().any(|e| !e.exact) {
+        println!(
+            "Estimates (depth-limited): {} {} {}",
+            color_by_verdict("Better", "Better"),
+            color_by_verdict("Even", "Even"),
+            color_by_verdict("Worse", "Worse")
+        );
+    }
+}
+
+pub fn print_review(review: &[ReviewedMove]) {
+    println!("--- Game Review ---");
+    for (move_idx, reviewed_move) in review.iter().enumerate() {
+        let annotation = match reviewed_move.annotation.as_str() {
+            "Best" => reviewed_move.annotation.green(),
+            "Inaccuracy" => reviewed_move.annotation.yellow(),
+            _ => reviewed_move.annotation.red(),
+        };
+        print!(
+            "{: >3}. {} {: >2}  {}",
+            move_idx + 1,
+            reviewed_move.player,
+            reviewed_move.board_move,
+            annotation
+        );
+        if !reviewed_move.better_moves.is_empty() {
+            print!(
+                " ({} -> {}), better: {}",
+                reviewed_move.verdict_before,
+                reviewed_move.verdict_after,
+                reviewed_move.better_moves.join(", ")
+            );
+        }
+        println!();
     }
 }
@@ -0,0 +1,91 @@
+use crate::analysis::{deserialize_analysis, deserialize_hint};
+use crate::game_state::deserialize_game_state;
+use crate::inputs::read_user_text;
+use crate::outputs::{print_analysis, print_hint};
+use tic_tac_toe_lib::{Game, MinimaxPlayer, Player, RandomPlayer, ScriptedPlayer};
+
+pub const PLAYER_KINDS: &str = "Human, Random, Minimax, Replay";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlayerKind {
+    Human,
+    Random,
+    Minimax,
+    Replay(Vec<String>),
+}
+
+pub struct HumanPlayer;
+
+impl Player for HumanPlayer {
+    // Commands that only inspect the game are handled here, the prompt is repeated after them
+    fn choose_move(&mut self, game: &Game) -> String {
+        // with gravity a move is a column, in wild the mark to place follows it
+        let state = deserialize_game_state(game.get_state().as_str());
+        let mut move_name = String::from(match state.playable_columns {
+            Some(_) => "column",
+            None => "move",
+        });
+        if state.placeable_marks.len() > 1 {
+            move_name += " followed by the mark ";
+            move_name += state.placeable_marks.join(" or ").as_str();
+        }
+        // swap is offered only instead of answering the first move
+        let mut commands = String::from("'hint', 'hint all', 'analyze', 'resign', 'offer draw'");
+        if state.swap_allowed {
+            commands += ", 'swap'";
+        }
+        loop {
+            // the mark is taken from the state, after a swap the player moves with the other one
+            let message = String::from("Player ")
+                + state.next_on_move.as_str()
+                + " please enter the "
+                + move_name.as_str()
+                + " (or "
+                + commands.as_str()
+                + ")";
+            let user_text = read_user_text(message.as_str(), true);
+
+            match user_text.trim().to_lowercase().as_str() {
+                "hint" | "hint all" => match game.get_hint() {
+                    Ok(hint) => print_hint(
+                        &deserialize_hint(hint.as_str()),
+                        user_text.trim().to_lowercase() == "hint all",
+                    ),
+                    Err(e) => println!("Error: {}", e),
+                },
+                "analyze" => match game.get_analysis() {
+                    Ok(analysis) => print_analysis(
+                        &deserialize_game_state(game.get_state().as_str()),
+                        &deserialize_analysis(analysis.as_str()),
+                    ),
+                    Err(e) => println!("Error: {}", e),
+                },
+                _ => return user_text,
+            }
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+// Replay moves are passed separately, so only the kind name is parsed here
+pub fn parse_player_kind(s: &str) -> Option<PlayerKind> {
+    match s.trim().to_lowercase().as_str() {
+        "" | "human" => Some(PlayerKind::Human),
+        "random" => Some(PlayerKind::Random),
+        "minimax" => Some(PlayerKind::Minimax),
+        "replay" => Some(PlayerKind::Replay(vec![])),
+        _ => None,
+    }
+}
+
+pub fn create_player(kind: &PlayerKind) -> Box<dyn Player> {
+    match kind {
+        PlayerKind::Human => Box::new(HumanPlayer),
+        PlayerKind::Random => Box::new(RandomPlayer::new()),
+        PlayerKind::Minimax => Box::new(MinimaxPlayer::new(None)),
+        PlayerKind::Replay(moves) => Box::new(ScriptedPlayer::new(moves.clone())),
+    }
+}
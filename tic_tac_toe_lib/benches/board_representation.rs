@@ -0,0 +1,95 @@
+// Bitboard against the previous double vector representation: a full game is played
+// on each board size and the result is calculated after every move, like Game does.
+// The bitboard is measured with a full check and with the incremental last-move check.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use tic_tac_toe_lib::benchmarks::{
+    calculate_result, calculate_result_after_move, calculate_result_by_scan, parse_board_dimension,
+    Board, CellGrid, PlayerMark, VecBoard,
+};
+use tic_tac_toe_lib::Outcome;
+
+// Cells visited in a scattered but fixed order, so no line is completed too early
+fn get_move_order(board_dim: usize) -> Vec<(usize, usize)> {
+    let cell_count = board_dim * board_dim;
+    (0..cell_count)
+        .map(|i| (i * 7 + 3) % cell_count)
+        .map(|cell_idx| (cell_idx / board_dim, cell_idx % board_dim))
+        .collect()
+}
+
+fn play_out<B: CellGrid>(
+    board: &mut B,
+    move_order: &[(usize, usize)],
+    calculate: fn(&B, usize, usize) -> Outcome,
+) -> Outcome {
+    let mut player_mark = PlayerMark::X;
+    for (row_idx, col_idx) in move_order {
+        board.set_cell(*row_idx, *col_idx, Some(player_mark.clone()));
+        let outcome = calculate(board, *row_idx, *col_idx);
+        if outcome.is_finished() {
+            return outcome;
+        }
+        player_mark = if player_mark == PlayerMark::X {
+            PlayerMark::O
+        } else {
+            PlayerMark::X
+        };
+    }
+    Outcome::InProgress
+}
+
+fn bench_board_representation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("play_out_with_result");
+
+    for board_dim in 3..=5u8 {
+        let dimension = parse_board_dimension(&board_dim.to_string()).unwrap();
+        let move_order = get_move_order(board_dim as usize);
+
+        group.bench_with_input(
+            BenchmarkId::new("vec_board_scan", board_dim),
+            &move_order,
+            |b, move_order| {
+                b.iter(|| {
+                    let mut board = VecBoard::new(dimension.clone());
+                    black_box(play_out(&mut board, move_order, |board, _, _| {
+                        calculate_result_by_scan(board)
+                    }))
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("bitboard_masks", board_dim),
+            &move_order,
+            |b, move_order| {
+                b.iter(|| {
+                    let mut board = Board::new(dimension.clone());
+                    black_box(play_out(&mut board, move_order, |board, _, _| {
+                        calculate_result(board, false)
+                    }))
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("bitboard_incremental", board_dim),
+            &move_order,
+            |b, move_order| {
+                b.iter(|| {
+                    let mut board = Board::new(dimension.clone());
+                    black_box(play_out(
+                        &mut board,
+                        move_order,
+                        |board, row_idx, col_idx| {
+                            calculate_result_after_move(board, row_idx, col_idx, false)
+                        },
+                    ))
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_board_representation);
+criterion_main!(benches);
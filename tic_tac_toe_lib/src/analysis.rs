@@ -0,0 +1,364 @@
+// NOTE 11: Analysis of a position, returned as JSON like the game state.
+//          Exact on 3x3, depth-limited estimate on bigger boards.
+
+use crate::game::Game;
+#[cfg(test)]
+use crate::search::WIN_SCORE;
+use crate::search::{get_default_depth, get_moves_to_end, is_decisive, score_moves};
+use crate::types::errors::TicTacToeError;
+use crate::types::game_action::parse_history_entry;
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Hint {
+    pub best_moves: Vec<String>,
+    pub verdict: String,
+    pub moves_to_end: Option<u32>,
+    pub exact: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct MoveEvaluation {
+    pub board_move: String,
+    pub score: i32,
+    pub verdict: String,
+    pub moves_to_end: Option<u32>,
+    pub exact: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ReviewedMove {
+    pub player: String,
+    pub board_move: String,
+    pub verdict_before: String,
+    pub verdict_after: String,
+    pub annotation: String,
+    pub better_moves: Vec<String>,
+}
+
+pub fn get_hint(game: &Game) -> Result<Hint, TicTacToeError> {
+    if game.is_finished() {
+        bail!("Game is finished, there is no move to hint");
+    }
+
+    let evaluations = get_analysis(game)?;
+    let best = evaluations.iter().max_by_key(|e| e.score).unwrap();
+
+    Ok(Hint {
+        best_moves: evaluations
+            .iter()
+            .filter(|e| e.score == best.score)
+            .map(|e| e.board_move.clone())
+            .collect(),
+        verdict: best.verdict.clone(),
+        moves_to_end: best.moves_to_end,
+        exact: best.exact,
+    })
+}
+
+// Evaluation of every legal move for the player on move
+pub fn get_analysis(game: &Game) -> Result<Vec<MoveEvaluation>, TicTacToeError> {
+    if game.is_finished() {
+        bail!("Game is finished, there is no move to analyze");
+    }
+
+    // a tablebase knows every position, otherwise bigger boards are searched only so deep
+    let (scores, searched_to_end) = match game
+        .get_tablebase()
+        .and_then(|tablebase| tablebase.score_moves(game))
+    {
+        Some(scores) => (scores, true),
+        None => {
+            // every ply fills a cell, legal moves are columns with gravity and doubled in wild
+            let depth = get_default_depth(game);
+            let scores = score_moves(game, depth);
+            let searched_to_end = depth as u32 >= game.get_board().get_empty_cell_count();
+            (scores, searched_to_end)
+        }
+    };
+
+    Ok(scores
+        .into_iter()
+        .map(|(board_move, score)| {
+            let exact = searched_to_end || is_decisive(score);
+            MoveEvaluation {
+                board_move,
+                score,
+                verdict: get_verdict(score, exact),
+                moves_to_end: get_decisive_moves_to_end(score),
+                exact,
+            }
+        })
+        .collect())
+}
+
+// Replays the game and compares every played move with the best one in that position
+pub fn get_review(game: &Game) -> Result<Vec<ReviewedMove>, TicTacToeError> {
+    if !game.is_finished() {
+        bail!("Game is not finished, it can not be reviewed");
+    }
+
+    let mut reviewed_moves: Vec<ReviewedMove> = vec![];
+    for (move_idx, board_move) in game.get_moves().iter().enumerate() {
+        // resignations, draw offers and swaps are not evaluated
+        if parse_history_entry(board_move).is_some() {
+            continue;
+        }
+        let position = game.replay(move_idx);
+        let evaluations = get_analysis(&position)?;
+        let best = evaluations.iter().max_by_key(|e| e.score).unwrap();
+        let played = evaluations
+            .iter()
+            .find(|e| e.board_move == *board_move)
+            .unwrap();
+
+        let annotation = get_annotation(best.score, played.score);
+        let better_moves = if annotation == "Best" {
+            vec![]
+        } else {
+            evaluations
+                .iter()
+                .filter(|e| e.score == best.score)
+                .map(|e| e.board_move.clone())
+                .collect()
+        };
+
+        reviewed_moves.push(ReviewedMove {
+            player: position.get_next_on_move().to_string(),
+            board_move: board_move.clone(),
+            verdict_before: best.verdict.clone(),
+            verdict_after: played.verdict.clone(),
+            annotation,
+            better_moves,
+        });
+    }
+
+    Ok(reviewed_moves)
+}
+
+// Blunder turns a win or draw into a loss, Inaccuracy turns a win into a draw.
+// Estimates count as draws, so on boards not searched to the end a move is only an
+// Inaccuracy when the search proves the win it gave away, wins found deeper are missed.
+fn get_annotation(best_score: i32, played_score: i32) -> String {
+    let best_outcome = get_outcome(best_score);
+    let played_outcome = get_outcome(played_score);
+
+    let annotation = if played_outcome == best_outcome {
+        "Best"
+    } else if played_outcome < 0 {
+        "Blunder"
+    } else {
+        "Inaccuracy"
+    };
+    String::from(annotation)
+}
+
+// 1 for a forced win, -1 for a forced loss, 0 otherwise
+fn get_outcome(score: i32) -> i32 {
+    if is_decisive(score) {
+        score.signum()
+    } else {
+        0
+    }
+}
+
+// Win/Draw/Loss when known, otherwise Better/Even/Worse as an estimate
+fn get_verdict(score: i32, exact: bool) -> String {
+    let verdict = if is_decisive(score) {
+        if score > 0 {
+            "Win"
+        } else {
+            "Loss"
+        }
+    } else if exact {
+        "Draw"
+    } else if score > 0 {
+        "Better"
+    } else if score < 0 {
+        "Worse"
+    } else {
+        "Even"
+    };
+    String::from(verdict)
+}
+
+fn get_decisive_moves_to_end(score: i32) -> Option<u32> {
+    if is_decisive(score) {
+        Some(get_moves_to_end(score))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_get_hint_empty_board() {
+    let g = Game::new("X,3").unwrap();
+    let hint = get_hint(&g).unwrap();
+
+    assert_eq!(hint.best_moves.len(), 9);
+    assert_eq!(hint.verdict, "Draw");
+    assert_eq!(hint.moves_to_end, None);
+    assert!(hint.exact);
+}
+
+#[test]
+fn test_get_hint_forced_win() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("3").unwrap();
+
+    // 9 wins at once, 4 and 7 win in 3 moves
+    let hint = get_hint(&g).unwrap();
+    assert_eq!(hint.best_moves, vec!["9"]);
+    assert_eq!(hint.verdict, "Win");
+    assert_eq!(hint.moves_to_end, Some(1));
+}
+
+#[test]
+fn test_get_hint_forced_loss() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("9").unwrap();
+    g.play_move("7").unwrap();
+
+    let hint = get_hint(&g).unwrap();
+    assert_eq!(hint.verdict, "Loss");
+    assert_eq!(hint.moves_to_end, Some(2));
+}
+
+#[test]
+fn test_get_hint_bigger_board_is_estimate() {
+    let g = Game::new("X,4").unwrap();
+    let hint = get_hint(&g).unwrap();
+
+    assert!(!hint.exact);
+    assert_eq!(hint.moves_to_end, None);
+}
+
+#[test]
+fn test_get_hint_gravity_is_estimate() {
+    // 4 columns to choose from, but 16 cells to fill
+    let g = Game::new("X,4,gravity=On").unwrap();
+    let hint = get_hint(&g).unwrap();
+    assert!(!hint.exact);
+    assert_ne!(hint.verdict, "Draw");
+}
+
+#[test]
+fn test_get_analysis_every_move() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("3").unwrap();
+
+    let evaluations = get_analysis(&g).unwrap();
+    assert_eq!(evaluations.len(), 5);
+
+    let verdicts: Vec<(&str, &str)> = evaluations
+        .iter()
+        .map(|e| (e.board_move.as_str(), e.verdict.as_str()))
+        .collect();
+    assert_eq!(
+        verdicts,
+        vec![
+            ("4", "Win"),
+            ("6", "Win"),
+            ("7", "Win"),
+            ("8", "Draw"),
+            ("9", "Win")
+        ]
+    );
+    assert!(evaluations.iter().all(|e| e.exact));
+}
+
+#[test]
+#[should_panic(expected = "Game is finished, there is no move to analyze")]
+fn test_get_analysis_finished() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("4").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("3").unwrap();
+
+    get_analysis(&g).unwrap();
+}
+
+#[test]
+fn test_get_review_blunder() {
+    let mut g = Game::new("X,3").unwrap();
+    for board_move in ["1", "2", "5", "9", "7", "3", "4"] {
+        g.play_move(board_move).unwrap();
+    }
+
+    let review = get_review(&g).unwrap();
+    assert_eq!(review.len(), 7);
+
+    let annotations: Vec<&str> = review.iter().map(|r| r.annotation.as_str()).collect();
+    assert_eq!(
+        annotations,
+        vec!["Best", "Blunder", "Best", "Best", "Best", "Best", "Best"]
+    );
+
+    assert_eq!(
+        review[1],
+        ReviewedMove {
+            player: String::from("O"),
+            board_move: String::from("2"),
+            verdict_before: String::from("Draw"),
+            verdict_after: String::from("Loss"),
+            annotation: String::from("Blunder"),
+            better_moves: vec![String::from("5")],
+        }
+    );
+}
+
+#[test]
+fn test_get_annotation() {
+    assert_eq!(get_annotation(WIN_SCORE - 1, WIN_SCORE - 3), "Best");
+    assert_eq!(get_annotation(WIN_SCORE - 1, 0), "Inaccuracy");
+    assert_eq!(get_annotation(WIN_SCORE - 1, -WIN_SCORE + 2), "Blunder");
+    assert_eq!(get_annotation(0, -WIN_SCORE + 2), "Blunder");
+    assert_eq!(get_annotation(0, -5), "Best");
+}
+
+#[test]
+fn test_get_review_skips_actions() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("5").unwrap();
+    g.offer_draw("O").unwrap();
+    g.play_move("1").unwrap();
+    g.resign("X").unwrap();
+
+    let review = get_review(&g).unwrap();
+    let moves: Vec<&str> = review.iter().map(|r| r.board_move.as_str()).collect();
+    assert_eq!(moves, vec!["5", "1"]);
+}
+
+#[test]
+#[should_panic(expected = "Game is not finished, it can not be reviewed")]
+fn test_get_review_not_finished() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+
+    get_review(&g).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Game is finished, there is no move to hint")]
+fn test_get_hint_finished() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("4").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("3").unwrap();
+
+    get_hint(&g).unwrap();
+}
@@ -1,47 +1,158 @@
 // NOTE 07: Public type. It can be created only through parse.
 
-use crate::game_state::{deserialize_game_state, GameState};
-use crate::settings::parse;
-use crate::state_calculation::calculate_result;
-use crate::types::board::Board;
-use crate::types::board_move::parse as parse_board_move;
+use crate::analysis::{get_analysis, get_hint, get_review};
+#[cfg(test)]
+use crate::game_state::deserialize_game_state;
+use crate::game_state::{GameState, OutcomeState, RemainingTime};
+use crate::settings::{parse, Settings};
+use crate::solver::Tablebase;
+#[cfg(test)]
+use crate::state_calculation::{
+    calculate_result, calculate_result_by_scan, get_winning_lines, get_wrapped_winning_lines,
+};
+use crate::state_calculation::{calculate_result_after_move, is_dead_position};
+use crate::timer::Timer;
+use crate::types::board::{Board, CellGrid};
+use crate::types::board_move::{parse_column, parse_playable};
+use crate::types::clock::{Clock, SystemClock};
 use crate::types::errors::TicTacToeError;
-use crate::types::player_mark::PlayerMark;
+use crate::types::game_action::{get_history_entry, parse_history_entry, GameAction};
+use crate::types::marked_move::split_mark;
+use crate::types::outcome::{DrawReason, Outcome, WinReason};
+use crate::types::player_mark::{get_two_player_marks, parse as parse_player_mark, PlayerMark};
+use crate::types::variant::Variant;
+use crate::types::win_rule::WinRule;
 use anyhow::bail;
+#[cfg(test)]
+use proptest::prelude::*;
+use std::sync::Arc;
+use std::time::Duration;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Game {
+    settings: Settings,
     board: Board,
+    // Players are named by their own mark, in wild either of them places either mark.
+    // Outcomes, turns, clocks and draw offers are about players, the board about marks.
     next_on_move: PlayerMark,
-    finished: bool,
-    result: Option<String>,
+    outcome: Outcome,
+    draw_offer: Option<PlayerMark>,
+    // Players exchanged marks after the first move
+    swapped: bool,
+    // Players skipped in the turn order, in the order they left the game
+    eliminated: Vec<PlayerMark>,
+    moves: Vec<String>,
+    // Only with a time control
+    timer: Option<Timer>,
+    // Only when set, hints, reviews and computer players then need no search
+    tablebase: Option<Arc<Tablebase>>,
 }
 
 impl Game {
     pub fn new(settings_str: &str) -> Result<Game, TicTacToeError> {
+        Game::with_clock(settings_str, Arc::new(SystemClock::new()))
+    }
+
+    // The clock is used only when the settings have a time control
+    pub fn with_clock(settings_str: &str, clock: Arc<dyn Clock>) -> Result<Game, TicTacToeError> {
         let settings = parse(settings_str)?;
+        if !matches!(settings.variant, Variant::Classic | Variant::Wild) {
+            bail!("Variant '{}' has its own game type", settings.variant);
+        }
+        if settings.board_count.get_value() > 1 {
+            bail!("More than one board can be played only in Notakto");
+        }
+        if settings.turn_order.get_players().len() > 2
+            && (settings.variant != Variant::Classic
+                || settings.misere
+                || settings.time_control.is_some())
+        {
+            bail!("More than two players can play only Classic without Misere and Time Control");
+        }
+        if settings.swap && settings.turn_order.get_players().len() > 2 {
+            bail!("Swap can be played only in a game of two players");
+        }
+        if settings.gravity
+            && settings
+                .blocked_cells
+                .get_mask_with_gravity(&settings.board_dimension)
+                .count_ones()
+                == settings.board_dimension.get_cell_count() as u32
+        {
+            bail!("Blocked Cells must leave some cells to play");
+        }
 
-        Ok(Game {
-            board: Board::new(settings.board_dimension),
-            next_on_move: settings.first_player,
-            finished: false,
-            result: None,
-        })
+        let mut game = Game::from_settings(settings);
+        game.timer = game
+            .settings
+            .time_control
+            .clone()
+            .map(|time_control| Timer::new(time_control, clock));
+        Ok(game)
+    }
+
+    fn from_settings(settings: Settings) -> Game {
+        Game {
+            board: Board::with_rules(
+                settings.board_dimension.clone(),
+                settings.get_line_length(),
+                match settings.gravity {
+                    true => settings
+                        .blocked_cells
+                        .get_mask_with_gravity(&settings.board_dimension),
+                    false => settings.blocked_cells.get_mask(),
+                },
+                settings.wrap,
+            ),
+            next_on_move: settings.first_player.clone(),
+            settings,
+            outcome: Outcome::InProgress,
+            draw_offer: None,
+            swapped: false,
+            eliminated: vec![],
+            moves: vec![],
+            timer: None,
+            tablebase: None,
+        }
     }
 
     pub fn play_move(&mut self, move_str: &str) -> Result<(), TicTacToeError> {
         // check if game is already finished
-        if self.finished {
+        if self.outcome.is_finished() {
             bail!("Game is finished, you can not play a move");
         }
 
-        // parse move - is str a number, is between 1 and 9/16/25
-        let board_move = parse_board_move(&self.board.dimension, move_str)?;
-        let (row_idx, col_idx) = board_move.get_indices();
+        // a move played after the time ran out loses the game
+        if self.is_out_of_time() {
+            self.outcome = self.get_outcome();
+            self.stop_timer_if_finished();
+            bail!("Player {} ran out of time", self.next_on_move);
+        }
+
+        // in wild the mover names the mark to place after the cell or column
+        let (move_str, placed_mark) = if self.is_wild() {
+            split_mark(move_str)?
+        } else {
+            (move_str, self.next_on_move.clone())
+        };
+
+        let (row_idx, col_idx) = if self.settings.gravity {
+            // parse column, the mark falls to its lowest empty cell
+            let col_idx = parse_column(&self.board.dimension, move_str)? as usize;
+            match self.get_drop_row(col_idx) {
+                Some(row_idx) => (row_idx, col_idx),
+                None => bail!("Column {} is already full", col_idx + 1),
+            }
+        } else {
+            // parse move - is str a number, is between 1 and 9/16/25, is not blocked
+            let board_move = parse_playable(&self.board, move_str)?;
+            let (row_idx, col_idx) = board_move.get_indices();
+            (row_idx as usize, col_idx as usize)
+        };
 
         // check if cell is filled
-        let cell = &mut self.board.cells[row_idx as usize][col_idx as usize];
-        match cell.get_value() {
+        #[allow(clippy::single_match, clippy::to_string_in_format_args)]
+        match self.board.get_cell(row_idx, col_idx) {
             Some(pm) => bail!(
                 "Cell {} is already filled with '{}'",
                 move_str,
@@ -50,36 +161,442 @@ impl Game {
             None => {}
         };
 
-        // update cell
-        cell.set_value(Some(self.next_on_move.clone()));
+        // update cell and history, columns are recorded with gravity and marks in wild
+        self.board
+            .set_cell(row_idx, col_idx, Some(placed_mark.clone()));
+        let board_width = self.board.dimension.get_width() as usize;
+        let position = if self.settings.gravity {
+            col_idx + 1
+        } else {
+            row_idx * board_width + col_idx + 1
+        };
+        self.moves.push(match self.is_wild() {
+            true => format!("{}{}", position, placed_mark),
+            false => position.to_string(),
+        });
+
+        // a move instead of an answer declines the opponent's draw offer
+        if self
+            .draw_offer
+            .as_ref()
+            .is_some_and(|offered_by| *offered_by != self.next_on_move)
+        {
+            self.draw_offer = None;
+        }
+
+        // stop the clock, next in the turn order is on move
+        if let Some(timer) = &mut self.timer {
+            timer.switch(&self.next_on_move);
+        }
+        let mover = self.next_on_move.clone();
+        self.next_on_move = self.get_next_player(&mover);
+
+        // calculate winner, all filled -> finished, outcome
+        self.outcome = get_player_outcome(
+            calculate_result_after_move(&self.board, row_idx, col_idx, self.settings.misere),
+            &mover,
+        );
+
+        // with the elimination rule a line takes the player out instead of ending the game
+        if self.settings.win_rule == WinRule::Elimination
+            && self.settings.turn_order.get_players().len() > 2
+            && matches!(self.outcome, Outcome::Win { .. })
+        {
+            self.eliminate(&mover, WinReason::Elimination);
+        }
+
+        // optional rule: draw as soon as no line can be completed anymore
+        if !self.outcome.is_finished()
+            && self.settings.early_draw
+            && is_dead_position(&self.board, &self.get_remaining_players())
+        {
+            self.outcome = Outcome::Draw {
+                reason: DrawReason::DeadPosition,
+            };
+        }
+
+        self.stop_timer_if_finished();
+        Ok(())
+    }
+
+    // Allowed at any time, not only for the player on move
+    pub fn resign(&mut self, mark_str: &str) -> Result<(), TicTacToeError> {
+        let player_mark = parse_acting_mark(mark_str)?;
+        self.apply_action(&player_mark, GameAction::Resign)
+    }
+
+    // Only the player on move may offer. The offer stays open until the opponent
+    // accepts, declines or plays a move.
+    pub fn offer_draw(&mut self, mark_str: &str) -> Result<(), TicTacToeError> {
+        let player_mark = parse_acting_mark(mark_str)?;
+        self.apply_action(&player_mark, GameAction::OfferDraw)
+    }
+
+    pub fn accept_draw(&mut self, mark_str: &str) -> Result<(), TicTacToeError> {
+        let player_mark = parse_acting_mark(mark_str)?;
+        self.apply_action(&player_mark, GameAction::AcceptDraw)
+    }
+
+    pub fn decline_draw(&mut self, mark_str: &str) -> Result<(), TicTacToeError> {
+        let player_mark = parse_acting_mark(mark_str)?;
+        self.apply_action(&player_mark, GameAction::DeclineDraw)
+    }
+
+    // Pie rule: the second player takes over the first move, the players exchange marks
+    // and the player who moved first answers it with the other mark
+    pub fn swap(&mut self, mark_str: &str) -> Result<(), TicTacToeError> {
+        let player_mark = parse_acting_mark(mark_str)?;
+        self.apply_action(&player_mark, GameAction::Swap)
+    }
+
+    fn apply_action(
+        &mut self,
+        player_mark: &PlayerMark,
+        action: GameAction,
+    ) -> Result<(), TicTacToeError> {
+        // the time may have run out before the action
+        self.outcome = self.get_outcome();
+        self.stop_timer_if_finished();
+        if self.outcome.is_finished() {
+            bail!(
+                "Game is finished, player {} can not act anymore",
+                player_mark
+            );
+        }
+        if !self.get_remaining_players().contains(player_mark) {
+            bail!("Player {} does not play in this game anymore", player_mark);
+        }
+        if matches!(
+            action,
+            GameAction::OfferDraw | GameAction::AcceptDraw | GameAction::DeclineDraw
+        ) && self.settings.turn_order.get_players().len() > 2
+        {
+            bail!("Draws can be offered only in a game of two players");
+        }
+
+        match action {
+            GameAction::Resign => {
+                self.draw_offer = None;
+                self.eliminate(player_mark, WinReason::Resignation);
+            }
+            GameAction::OfferDraw => {
+                if let Some(offered_by) = &self.draw_offer {
+                    bail!("Player {} already offered a draw", offered_by);
+                }
+                if *player_mark != self.next_on_move {
+                    bail!("Player {} can offer a draw only when on move", player_mark);
+                }
+                self.draw_offer = Some(player_mark.clone());
+            }
+            GameAction::AcceptDraw | GameAction::DeclineDraw => {
+                if self.draw_offer != Some(player_mark.get_opponent()) {
+                    bail!("There is no draw offer for player {}", player_mark);
+                }
+                self.draw_offer = None;
+                if action == GameAction::AcceptDraw {
+                    self.outcome = Outcome::Draw {
+                        reason: DrawReason::Agreement,
+                    };
+                }
+            }
+            GameAction::Swap => {
+                if !self.settings.swap {
+                    bail!("Swap is allowed only with the Swap setting");
+                }
+                if *player_mark != self.next_on_move || !self.is_swap_allowed() {
+                    bail!(
+                        "Player {} can swap only instead of answering the first move",
+                        player_mark
+                    );
+                }
+                // an offer made before the swap was made for the other mark
+                self.draw_offer = None;
+                self.swapped = true;
+                if let Some(timer) = &mut self.timer {
+                    timer.swap(player_mark);
+                }
+            }
+        }
+
+        self.moves.push(get_history_entry(player_mark, action));
+        self.stop_timer_if_finished();
+        Ok(())
+    }
 
-        // toggle next on move
-        self.next_on_move = if self.next_on_move == PlayerMark::X {
-            PlayerMark::O
+    pub fn legal_moves(&self) -> Vec<String> {
+        if self.is_finished() {
+            return vec![];
+        }
+
+        let mut positions: Vec<String> = vec![];
+        if self.settings.gravity {
+            positions = self
+                .get_playable_columns()
+                .iter()
+                .map(|col_number| col_number.to_string())
+                .collect();
         } else {
-            PlayerMark::X
+            let board_width = self.board.dimension.get_width();
+            let board_height = self.board.dimension.get_height();
+
+            for row_idx in 0..board_height {
+                for col_idx in 0..board_width {
+                    let (r, c) = (row_idx as usize, col_idx as usize);
+                    if self.board.get_cell(r, c).is_none() && !self.board.is_blocked(r, c) {
+                        positions.push((row_idx * board_width + col_idx + 1).to_string());
+                    }
+                }
+            }
+        }
+
+        if !self.is_wild() {
+            return positions;
+        }
+        positions
+            .iter()
+            .flat_map(|position| {
+                get_two_player_marks()
+                    .into_iter()
+                    .map(move |pm| format!("{}{}", position, pm))
+            })
+            .collect()
+    }
+
+    // Marks the player on move may place
+    fn get_placeable_marks(&self) -> Vec<PlayerMark> {
+        match self.is_wild() {
+            true => get_two_player_marks(),
+            false => vec![self.next_on_move.clone()],
+        }
+    }
+
+    // Next player in the turn order that is still in the game
+    fn get_next_player(&self, player_mark: &PlayerMark) -> PlayerMark {
+        let turn_order = &self.settings.turn_order;
+        let mut next = turn_order.get_next(player_mark);
+        while self.eliminated.contains(&next) && next != *player_mark {
+            next = turn_order.get_next(&next);
+        }
+        next
+    }
+
+    fn get_remaining_players(&self) -> Vec<PlayerMark> {
+        self.settings
+            .turn_order
+            .get_players()
+            .iter()
+            .filter(|pm| !self.eliminated.contains(pm))
+            .cloned()
+            .collect()
+    }
+
+    // Takes the player out of the game, the last player remaining wins.
+    // In a game of two players that is the opponent.
+    fn eliminate(&mut self, player_mark: &PlayerMark, reason: WinReason) {
+        self.eliminated.push(player_mark.clone());
+        if self.next_on_move == *player_mark {
+            self.next_on_move = self.get_next_player(player_mark);
+        }
+
+        let remaining_players = self.get_remaining_players();
+        self.outcome = if remaining_players.len() == 1 {
+            Outcome::Win {
+                winner: remaining_players[0].clone(),
+                reason,
+                line: vec![],
+            }
+        } else if self.board.is_full() {
+            Outcome::Draw {
+                reason: DrawReason::BoardFull,
+            }
+        } else {
+            Outcome::InProgress
         };
+    }
+
+    // Only the first board move can be taken over, and only once
+    fn is_swap_allowed(&self) -> bool {
+        let board_moves = self
+            .moves
+            .iter()
+            .filter(|entry| parse_history_entry(entry).is_none())
+            .count();
+        self.settings.swap && !self.swapped && !self.is_finished() && board_moves == 1
+    }
+
+    // Cells below a blocked cell are blocked too with gravity
+    fn get_blocked_cell_numbers(&self) -> Vec<u8> {
+        let board_width = self.board.dimension.get_width();
+        let board_height = self.board.dimension.get_height();
+        (0..board_height)
+            .flat_map(|row_idx| (0..board_width).map(move |col_idx| (row_idx, col_idx)))
+            .filter(|(row_idx, col_idx)| {
+                self.board.is_blocked(*row_idx as usize, *col_idx as usize)
+            })
+            .map(|(row_idx, col_idx)| row_idx * board_width + col_idx + 1)
+            .collect()
+    }
+
+    fn is_wild(&self) -> bool {
+        self.settings.variant == Variant::Wild
+    }
+
+    // JSON with the best moves for the player on move and the expected outcome
+    pub fn get_hint(&self) -> Result<String, TicTacToeError> {
+        let hint = get_hint(self)?;
+        Ok(serde_json::to_string(&hint).unwrap())
+    }
 
-        // calculate winner, all filled -> finished, result
-        let (finished, result) = calculate_result(&self.board);
-        self.finished = finished;
-        self.result = result;
+    // JSON with an evaluation of every legal move for the player on move
+    pub fn get_analysis(&self) -> Result<String, TicTacToeError> {
+        let evaluations = get_analysis(self)?;
+        Ok(serde_json::to_string(&evaluations).unwrap())
+    }
+
+    // JSON with every move of a finished game annotated as Best, Inaccuracy or Blunder.
+    // Complete only where the analysis is exact, see get_annotation.
+    pub fn get_review(&self) -> Result<String, TicTacToeError> {
+        let review = get_review(self)?;
+        Ok(serde_json::to_string(&review).unwrap())
+    }
+
+    // Cell the mark falls to, it stops on the first filled or blocked cell of the column.
+    // None for a full column.
+    fn get_drop_row(&self, col_idx: usize) -> Option<usize> {
+        let board_height = self.board.dimension.get_height() as usize;
+        (0..board_height)
+            .take_while(|row_idx| {
+                self.board.get_cell(*row_idx, col_idx).is_none()
+                    && !self.board.is_blocked(*row_idx, col_idx)
+            })
+            .last()
+    }
+
+    // Column numbers counted from 1, only used with gravity
+    fn get_playable_columns(&self) -> Vec<u8> {
+        let board_width = self.board.dimension.get_width();
+        (0..board_width)
+            .filter(|col_idx| self.get_drop_row(*col_idx as usize).is_some())
+            .map(|col_idx| col_idx + 1)
+            .collect()
+    }
 
+    // Clocks run from here or from the first move, players can be set up before
+    pub fn start_clock(&mut self) {
+        if let Some(timer) = &mut self.timer {
+            timer.start();
+        }
+    }
+
+    // Time the player on move can spend on this move, None without a time control
+    pub fn get_time_budget(&self) -> Option<Duration> {
+        // legal moves are columns with gravity and doubled in wild, every move fills a cell
+        let moves_left = self.board.get_empty_cell_count().div_ceil(2);
+        self.timer
+            .as_ref()
+            .map(|timer| timer.get_budget(&self.next_on_move, moves_left))
+    }
+
+    // Tablebase has to be solved with the settings of this game
+    pub fn set_tablebase(&mut self, tablebase: Arc<Tablebase>) -> Result<(), TicTacToeError> {
+        if !tablebase.is_solved_for(&self.settings) {
+            bail!("Tablebase was solved for other settings");
+        }
+        self.tablebase = Some(tablebase);
         Ok(())
     }
 
+    // Copy for searching ahead, the search itself must not run out of time
+    pub(crate) fn without_timer(&self) -> Game {
+        Game {
+            timer: None,
+            tablebase: None,
+            ..self.clone()
+        }
+    }
+
+    // Fresh game with the same settings and the first move_count history entries replayed.
+    // Replayed games have no clock.
+    pub(crate) fn replay(&self, move_count: usize) -> Game {
+        let mut game = Game::from_settings(self.settings.clone());
+        game.tablebase = self.tablebase.clone();
+        for entry in &self.moves[..move_count] {
+            match parse_history_entry(entry) {
+                Some((player_mark, action)) => game.apply_action(&player_mark, action),
+                None => game.play_move(entry),
+            }
+            .unwrap();
+        }
+        game
+    }
+
+    pub(crate) fn get_tablebase(&self) -> Option<&Tablebase> {
+        self.tablebase.as_deref()
+    }
+
+    pub(crate) fn get_settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub(crate) fn get_moves(&self) -> &Vec<String> {
+        &self.moves
+    }
+
+    pub(crate) fn get_board(&self) -> &Board {
+        &self.board
+    }
+
+    pub(crate) fn get_next_on_move(&self) -> &PlayerMark {
+        &self.next_on_move
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.outcome.is_finished() || self.is_out_of_time()
+    }
+
+    // A flag fall ends the game even before the player out of time tries to move
+    pub fn get_outcome(&self) -> Outcome {
+        match self.is_out_of_time() {
+            true => Outcome::Win {
+                winner: self.next_on_move.get_opponent(),
+                reason: WinReason::Timeout,
+                line: vec![],
+            },
+            false => self.outcome.clone(),
+        }
+    }
+
+    fn is_out_of_time(&self) -> bool {
+        !self.outcome.is_finished()
+            && self
+                .timer
+                .as_ref()
+                .is_some_and(|timer| timer.is_out_of_time(&self.next_on_move))
+    }
+
+    // No clock runs after the game is over
+    fn stop_timer_if_finished(&mut self) {
+        if let Some(timer) = &mut self.timer {
+            if self.outcome.is_finished() {
+                timer.stop(&self.next_on_move);
+            }
+        }
+    }
+
     pub fn get_state(&self) -> String {
-        let board_dim = self.board.dimension.get_value();
+        let board_width = self.board.dimension.get_width();
+        let board_height = self.board.dimension.get_height();
 
         let mut bd: Vec<Vec<String>> = vec![];
-        for row_idx in 0..board_dim {
+        for row_idx in 0..board_height {
             let mut row: Vec<String> = vec![];
-            for col_idx in 0..board_dim {
-                let cell_value = self.board.cells[row_idx as usize][col_idx as usize]
-                    .get_value()
-                    .clone();
+            for col_idx in 0..board_width {
+                let cell_value = self.board.get_cell(row_idx as usize, col_idx as usize);
                 let str = match cell_value {
-                    None => (row_idx * board_dim + col_idx + 1).to_string(),
+                    None if self.board.is_blocked(row_idx as usize, col_idx as usize) => {
+                        String::from("#")
+                    }
+                    None => (row_idx * board_width + col_idx + 1).to_string(),
                     Some(pm) => pm.to_string(),
                 };
                 row.push(str);
@@ -87,27 +604,88 @@ impl Game {
             bd.push(row);
         }
 
-        let res: String = match &self.result {
-            None => String::from(""),
-            Some(res) => res.to_string(),
+        let outcome = self.get_outcome();
+        let remaining_time = match &self.timer {
+            None => vec![],
+            Some(timer) => self
+                .settings
+                .turn_order
+                .get_players()
+                .iter()
+                .map(|pm| RemainingTime {
+                    milliseconds: timer.get_remaining(pm, &self.next_on_move).as_millis() as u64,
+                    mark: pm.to_string(),
+                })
+                .collect(),
         };
 
         let bs = GameState {
             board: bd,
             next_on_move: self.next_on_move.to_string(),
-            finished: self.finished,
-            result: res,
+            finished: outcome.is_finished(),
+            outcome: OutcomeState::from(&outcome),
+            draw_offer: self.draw_offer.as_ref().map(|pm| pm.to_string()),
+            moves: self.moves.clone(),
+            remaining_time,
+            playable_columns: match self.settings.gravity {
+                true if outcome.is_finished() => Some(vec![]),
+                true => Some(self.get_playable_columns()),
+                false => None,
+            },
+            placeable_marks: match outcome.is_finished() {
+                true => vec![],
+                false => self
+                    .get_placeable_marks()
+                    .iter()
+                    .map(|pm| pm.to_string())
+                    .collect(),
+            },
+            players: self
+                .settings
+                .turn_order
+                .get_players()
+                .iter()
+                .map(|pm| pm.to_string())
+                .collect(),
+            eliminated: self.eliminated.iter().map(|pm| pm.to_string()).collect(),
+            blocked_cells: self.get_blocked_cell_numbers(),
+            swap_allowed: self.is_swap_allowed(),
+            swapped: self.swapped,
         };
 
         serde_json::to_string(&bs).unwrap()
     }
 }
 
+// Board outcomes name the mark of the line, game outcomes the player. Only the player
+// who moved can complete a line, in wild whatever mark the line has.
+fn get_player_outcome(board_outcome: Outcome, mover: &PlayerMark) -> Outcome {
+    match board_outcome {
+        Outcome::Win { reason, line, .. } => Outcome::Win {
+            winner: match reason {
+                WinReason::Misere => mover.get_opponent(),
+                _ => mover.clone(),
+            },
+            reason,
+            line,
+        },
+        outcome => outcome,
+    }
+}
+
+// Actions name the acting player, an empty mark must not fall back to the default X
+fn parse_acting_mark(mark_str: &str) -> Result<PlayerMark, TicTacToeError> {
+    if mark_str.trim().is_empty() {
+        bail!("Player mark is missing");
+    }
+    parse_player_mark(mark_str)
+}
+
 #[test]
 fn test_new_state() {
     let g = Game::new(" o , 3 ").unwrap();
 
-    let s = "{\"board\":[[\"1\",\"2\",\"3\"],[\"4\",\"5\",\"6\"],[\"7\",\"8\",\"9\"]],\"next_on_move\":\"O\",\"finished\":false,\"result\":\"\"}";
+    let s = "{\"board\":[[\"1\",\"2\",\"3\"],[\"4\",\"5\",\"6\"],[\"7\",\"8\",\"9\"]],\"next_on_move\":\"O\",\"finished\":false,\"outcome\":{\"status\":\"InProgress\",\"winner\":null,\"reason\":null,\"line\":[]},\"draw_offer\":null,\"moves\":[],\"remaining_time\":[],\"playable_columns\":null,\"placeable_marks\":[\"O\"],\"players\":[\"X\",\"O\"],\"eliminated\":[],\"blocked_cells\":[],\"swap_allowed\":false,\"swapped\":false}";
     let state_expected = deserialize_game_state(s);
 
     let state_actual = deserialize_game_state(&g.get_state());
@@ -122,7 +700,7 @@ fn test_play_moves_state() {
     g.play_move("5").unwrap();
     g.play_move("9").unwrap();
 
-    let s = "{\"board\":[[\"X\",\"2\",\"3\"],[\"4\",\"O\",\"6\"],[\"7\",\"8\",\"X\"]],\"next_on_move\":\"O\",\"finished\":false,\"result\":\"\"}";
+    let s = "{\"board\":[[\"X\",\"2\",\"3\"],[\"4\",\"O\",\"6\"],[\"7\",\"8\",\"X\"]],\"next_on_move\":\"O\",\"finished\":false,\"outcome\":{\"status\":\"InProgress\",\"winner\":null,\"reason\":null,\"line\":[]},\"draw_offer\":null,\"moves\":[\"1\",\"5\",\"9\"],\"remaining_time\":[],\"playable_columns\":null,\"placeable_marks\":[\"O\"],\"players\":[\"X\",\"O\"],\"eliminated\":[],\"blocked_cells\":[],\"swap_allowed\":false,\"swapped\":false}";
     let state_expected = deserialize_game_state(s);
 
     let state_actual = deserialize_game_state(&g.get_state());
@@ -139,7 +717,7 @@ fn test_restart_state() {
 
     g = Game::new(" o , 3 ").unwrap();
 
-    let s = "{\"board\":[[\"1\",\"2\",\"3\"],[\"4\",\"5\",\"6\"],[\"7\",\"8\",\"9\"]],\"next_on_move\":\"O\",\"finished\":false,\"result\":\"\"}";
+    let s = "{\"board\":[[\"1\",\"2\",\"3\"],[\"4\",\"5\",\"6\"],[\"7\",\"8\",\"9\"]],\"next_on_move\":\"O\",\"finished\":false,\"outcome\":{\"status\":\"InProgress\",\"winner\":null,\"reason\":null,\"line\":[]},\"draw_offer\":null,\"moves\":[],\"remaining_time\":[],\"playable_columns\":null,\"placeable_marks\":[\"O\"],\"players\":[\"X\",\"O\"],\"eliminated\":[],\"blocked_cells\":[],\"swap_allowed\":false,\"swapped\":false}";
     let state_expected = deserialize_game_state(s);
 
     let state_actual = deserialize_game_state(&g.get_state());
@@ -148,6 +726,7 @@ fn test_restart_state() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_detect_finished_winner_x() {
     let mut g = Game::new(" x , 3 ").unwrap();
     // X wins on row 0
@@ -159,10 +738,14 @@ fn test_detect_finished_winner_x() {
 
     let state_expected = deserialize_game_state(&g.get_state());
     assert_eq!(state_expected.finished, true);
-    assert_eq!(state_expected.result, "X");
+    assert_eq!(state_expected.outcome.status, "Win");
+    assert_eq!(state_expected.outcome.winner, Some(String::from("X")));
+    assert_eq!(state_expected.outcome.reason, Some(String::from("Line")));
+    assert_eq!(state_expected.outcome.line, vec![1, 2, 3]);
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_detect_finished_winner_o() {
     let mut g = Game::new(" x , 3 ").unwrap();
     // O wins on right diagonal
@@ -175,10 +758,12 @@ fn test_detect_finished_winner_o() {
 
     let state_expected = deserialize_game_state(&g.get_state());
     assert_eq!(state_expected.finished, true);
-    assert_eq!(state_expected.result, "O");
+    assert_eq!(state_expected.outcome.winner, Some(String::from("O")));
+    assert_eq!(state_expected.outcome.line, vec![3, 5, 7]);
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_detect_finished_tied() {
     let mut g = Game::new("O,3").unwrap();
     g.play_move("5").unwrap();
@@ -193,7 +778,69 @@ fn test_detect_finished_tied() {
 
     let state_expected = deserialize_game_state(&g.get_state());
     assert_eq!(state_expected.finished, true);
-    assert_eq!(state_expected.result, "Tied");
+    assert_eq!(state_expected.outcome.status, "Draw");
+    assert_eq!(state_expected.outcome.winner, None);
+    assert_eq!(
+        state_expected.outcome.reason,
+        Some(String::from("BoardFull"))
+    );
+}
+
+#[test]
+fn test_legal_moves() {
+    let mut g = Game::new(" x , 3 ").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("5").unwrap();
+
+    assert_eq!(g.legal_moves(), vec!["2", "3", "4", "6", "7", "8", "9"]);
+}
+
+#[test]
+fn test_legal_moves_finished() {
+    let mut g = Game::new(" x , 3 ").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("4").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("3").unwrap();
+
+    assert!(g.legal_moves().is_empty());
+}
+
+#[test]
+fn test_detect_dead_position_early_draw() {
+    let moves = ["1", "2", "3", "5", "4", "6", "8", "7"];
+
+    let mut g = Game::new("X,3,early_draw=On").unwrap();
+    for board_move in &moves[..7] {
+        g.play_move(board_move).unwrap();
+    }
+    assert!(!deserialize_game_state(&g.get_state()).finished);
+
+    // O blocks the last open line (left column), cell 9 is still empty
+    g.play_move(moves[7]).unwrap();
+    let state = deserialize_game_state(&g.get_state());
+    assert!(state.finished);
+    assert_eq!(state.outcome.status, "Draw");
+    assert_eq!(state.outcome.reason, Some(String::from("DeadPosition")));
+}
+
+#[test]
+fn test_no_early_draw_by_default() {
+    let mut g = Game::new("X,3").unwrap();
+    for board_move in ["1", "2", "3", "5", "4", "6", "8", "7"] {
+        g.play_move(board_move).unwrap();
+    }
+
+    let state = deserialize_game_state(&g.get_state());
+    assert!(!state.finished);
+    assert_eq!(state.outcome.reason, None);
+}
+
+#[test]
+#[should_panic(expected = "Variant 'Ultimate' has its own game type")]
+fn test_new_other_variant() {
+    let _g = Game::new("X,3,variant=Ultimate").unwrap();
 }
 
 #[test]
@@ -241,3 +888,836 @@ fn test_play_move_after_finished_tied() {
 
     g.play_move("1").unwrap();
 }
+
+#[test]
+fn test_resign() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("5").unwrap();
+    // O is not on move, but can resign anyway
+    g.play_move("1").unwrap();
+    g.resign("x").unwrap();
+
+    let state = deserialize_game_state(&g.get_state());
+    assert!(state.finished);
+    assert_eq!(state.outcome.winner, Some(String::from("O")));
+    assert_eq!(state.outcome.reason, Some(String::from("Resignation")));
+    assert!(state.outcome.line.is_empty());
+    assert_eq!(state.moves, vec!["5", "1", "X resigns"]);
+    assert!(g.legal_moves().is_empty());
+}
+
+#[test]
+fn test_draw_offer_accepted() {
+    let mut g = Game::new("X,3").unwrap();
+    g.offer_draw("X").unwrap();
+    g.play_move("5").unwrap();
+    assert_eq!(
+        deserialize_game_state(&g.get_state()).draw_offer,
+        Some(String::from("X"))
+    );
+
+    g.accept_draw("O").unwrap();
+    let state = deserialize_game_state(&g.get_state());
+    assert!(state.finished);
+    assert_eq!(state.outcome.status, "Draw");
+    assert_eq!(state.outcome.reason, Some(String::from("Agreement")));
+    assert_eq!(state.draw_offer, None);
+    assert_eq!(state.moves, vec!["X offers draw", "5", "O accepts draw"]);
+}
+
+#[test]
+fn test_draw_offer_declined() {
+    let mut g = Game::new("X,3").unwrap();
+    g.offer_draw("X").unwrap();
+    g.decline_draw("O").unwrap();
+    assert!(!g.is_finished());
+    assert!(g.accept_draw("O").is_err());
+
+    // playing a move also declines the offer
+    g.offer_draw("X").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("1").unwrap();
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.draw_offer, None);
+    assert_eq!(
+        state.moves,
+        vec![
+            "X offers draw",
+            "O declines draw",
+            "X offers draw",
+            "5",
+            "1"
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "There is no draw offer for player X")]
+fn test_accept_own_draw_offer() {
+    let mut g = Game::new("X,3").unwrap();
+    g.offer_draw("X").unwrap();
+    g.accept_draw("X").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Player O can offer a draw only when on move")]
+fn test_draw_offer_not_on_move() {
+    let mut g = Game::new("X,3").unwrap();
+    g.offer_draw("O").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Player mark is missing")]
+fn test_resign_without_mark() {
+    let mut g = Game::new("X,3").unwrap();
+    g.resign(" ").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Game is finished, player O can not act anymore")]
+fn test_resign_after_finished() {
+    let mut g = Game::new("X,3").unwrap();
+    g.resign("X").unwrap();
+    g.resign("O").unwrap();
+}
+
+#[test]
+fn test_replay_with_actions() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("5").unwrap();
+    g.offer_draw("O").unwrap();
+    g.play_move("1").unwrap();
+    g.resign("O").unwrap();
+
+    let replayed = g.replay(g.get_moves().len());
+    assert_eq!(replayed.get_state(), g.get_state());
+}
+
+#[test]
+fn test_time_control_remaining_time() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut g = Game::with_clock("X,3,time_control=10+1", clock.clone()).unwrap();
+    g.start_clock();
+    clock.advance(Duration::from_millis(2500));
+    g.play_move("5").unwrap();
+    clock.advance(Duration::from_secs(4));
+
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(
+        state.remaining_time,
+        vec![
+            RemainingTime {
+                mark: String::from("X"),
+                milliseconds: 8500,
+            },
+            RemainingTime {
+                mark: String::from("O"),
+                milliseconds: 6000,
+            },
+        ]
+    );
+    assert_eq!(g.get_time_budget(), Some(Duration::from_millis(2500)));
+}
+
+#[test]
+fn test_time_control_clock_starts_after_setup() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut g = Game::with_clock("X,3,time_control=10", clock.clone()).unwrap();
+    // players are chosen, nobody is charged
+    clock.advance(Duration::from_secs(30));
+    assert!(!g.is_finished());
+
+    g.start_clock();
+    clock.advance(Duration::from_secs(3));
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.remaining_time[0].milliseconds, 7000);
+    assert_eq!(state.remaining_time[1].milliseconds, 10000);
+}
+
+#[test]
+fn test_time_control_first_move_starts_clock() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut g = Game::with_clock("X,3,time_control=5/move", clock.clone()).unwrap();
+    clock.advance(Duration::from_secs(10));
+    g.play_move("5").unwrap();
+
+    clock.advance(Duration::from_secs(2));
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.remaining_time[1].milliseconds, 3000);
+}
+
+#[test]
+fn test_time_budget_counts_empty_cells() {
+    use crate::types::clock::ManualClock;
+
+    // 9 cells left, 5 of them for X, although wild has 18 legal moves
+    let g = Game::with_clock(
+        "X,3,variant=Wild,time_control=60",
+        Arc::new(ManualClock::new()),
+    )
+    .unwrap();
+    assert_eq!(g.get_time_budget(), Some(Duration::from_secs(12)));
+
+    // 16 cells left, 8 of them for X, although there are 4 columns to choose from
+    let g = Game::with_clock(
+        "X,4,gravity=On,time_control=60",
+        Arc::new(ManualClock::new()),
+    )
+    .unwrap();
+    assert_eq!(g.get_time_budget(), Some(Duration::from_millis(7500)));
+}
+
+#[test]
+fn test_time_control_timeout() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut g = Game::with_clock("X,3,time_control=5/move", clock.clone()).unwrap();
+    g.play_move("5").unwrap();
+    clock.advance(Duration::from_secs(5));
+
+    assert_eq!(
+        g.play_move("1").unwrap_err().to_string(),
+        "Player O ran out of time"
+    );
+    let state = deserialize_game_state(&g.get_state());
+    assert!(state.finished);
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.reason, Some(String::from("Timeout")));
+    assert_eq!(state.moves, vec!["5"]);
+}
+
+#[test]
+fn test_time_control_flag_fall_without_move() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut g = Game::with_clock("X,3,time_control=5/move", clock.clone()).unwrap();
+    g.play_move("5").unwrap();
+    clock.advance(Duration::from_secs(6));
+
+    // the state shows the timeout before O tries to move
+    let state = deserialize_game_state(&g.get_state());
+    assert!(state.finished);
+    assert_eq!(state.outcome.reason, Some(String::from("Timeout")));
+    assert!(g.legal_moves().is_empty());
+    assert!(g.resign("O").is_err());
+}
+
+#[test]
+fn test_time_control_clock_stops_when_finished() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut g = Game::with_clock("X,3,time_control=10", clock.clone()).unwrap();
+    g.play_move("5").unwrap();
+    clock.advance(Duration::from_secs(3));
+    g.resign("X").unwrap();
+    clock.advance(Duration::from_secs(60));
+
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.outcome.reason, Some(String::from("Resignation")));
+    assert_eq!(state.remaining_time[1].milliseconds, 7000);
+}
+
+#[test]
+fn test_no_time_control_by_default() {
+    let g = Game::new("X,3").unwrap();
+    assert_eq!(g.get_time_budget(), None);
+}
+
+#[test]
+fn test_misere_completing_line_loses() {
+    let mut g = Game::new("X,3,misere=On").unwrap();
+    // X completes the first row
+    for board_move in ["1", "4", "2", "5", "3"] {
+        g.play_move(board_move).unwrap();
+    }
+
+    let state = deserialize_game_state(&g.get_state());
+    assert!(state.finished);
+    assert_eq!(state.outcome.winner, Some(String::from("O")));
+    assert_eq!(state.outcome.reason, Some(String::from("Misere")));
+    assert_eq!(state.outcome.line, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_gravity_marks_fall_down() {
+    let mut g = Game::new("X,3,gravity=On").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("3").unwrap();
+
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.board[1], vec!["4", "O", "6"]);
+    assert_eq!(state.board[2], vec!["7", "X", "X"]);
+    assert_eq!(state.moves, vec!["2", "2", "3"]);
+    assert_eq!(state.playable_columns, Some(vec![1, 2, 3]));
+
+    g.play_move("2").unwrap();
+    assert_eq!(g.legal_moves(), vec!["1", "3"]);
+}
+
+#[test]
+#[should_panic(expected = "Column 1 is already full")]
+fn test_gravity_full_column() {
+    let mut g = Game::new("X,3,gravity=On").unwrap();
+    for _ in 0..4 {
+        g.play_move("1").unwrap();
+    }
+}
+
+#[test]
+fn test_gravity_win_on_bottom_row() {
+    let mut g = Game::new("X,4,gravity=On").unwrap();
+    for column in ["1", "1", "2", "2", "3", "3", "4"] {
+        g.play_move(column).unwrap();
+    }
+
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.line, vec![13, 14, 15, 16]);
+    assert_eq!(state.playable_columns, Some(vec![]));
+}
+
+#[test]
+fn test_rectangular_board() {
+    let mut g = Game::new("X,width=4,height=3").unwrap();
+    assert_eq!(g.legal_moves().len(), 12);
+
+    // lines are 3 long, so a row of 4 has two of them
+    for board_move in ["2", "5", "3", "6", "4"] {
+        g.play_move(board_move).unwrap();
+    }
+
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.board.len(), 3);
+    assert_eq!(state.board[1], vec!["O", "O", "7", "8"]);
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.line, vec![2, 3, 4]);
+}
+
+#[test]
+fn test_rectangular_board_with_gravity() {
+    let mut g = Game::new("X,width=7,height=6,gravity=On,win_length=4").unwrap();
+    assert_eq!(g.legal_moves().len(), 7);
+
+    g.play_move("7").unwrap();
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.board[5][6], "X");
+    assert_eq!(state.moves, vec!["7"]);
+
+    // four in the last column win, the column is six cells high
+    for column in ["1", "7", "1", "7", "1", "7"] {
+        g.play_move(column).unwrap();
+    }
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.line, vec![21, 28, 35, 42]);
+}
+
+#[test]
+fn test_wild_either_mark() {
+    let mut g = Game::new("X,3,variant=Wild").unwrap();
+    assert_eq!(g.legal_moves().len(), 18);
+    assert_eq!(g.legal_moves()[..2], ["1X", "1O"]);
+
+    g.play_move("5o").unwrap();
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.board[1][1], "O");
+    assert_eq!(state.next_on_move, "O");
+    assert_eq!(state.moves, vec!["5O"]);
+    assert_eq!(state.placeable_marks, vec!["X", "O"]);
+}
+
+#[test]
+#[should_panic(expected = "Move '5' must end with the mark to place, for example 5O")]
+fn test_wild_move_without_mark() {
+    let mut g = Game::new("X,3,variant=Wild").unwrap();
+    g.play_move("5").unwrap();
+}
+
+#[test]
+fn test_wild_player_completing_line_wins() {
+    let mut g = Game::new("X,3,variant=Wild").unwrap();
+    // O completes a line of X marks
+    for board_move in ["1X", "5O", "2X", "3X"] {
+        g.play_move(board_move).unwrap();
+    }
+
+    assert_eq!(
+        g.get_outcome(),
+        Outcome::Win {
+            winner: PlayerMark::O,
+            reason: WinReason::Line,
+            line: vec![1, 2, 3],
+        }
+    );
+}
+
+#[test]
+fn test_wild_misere_player_completing_line_loses() {
+    let mut g = Game::new("X,3,variant=Wild,misere=On").unwrap();
+    // player X completes a line of O marks
+    for board_move in ["1O", "5X", "2O", "9X", "3O"] {
+        g.play_move(board_move).unwrap();
+    }
+
+    assert_eq!(
+        g.get_outcome(),
+        Outcome::Win {
+            winner: PlayerMark::O,
+            reason: WinReason::Misere,
+            line: vec![1, 2, 3],
+        }
+    );
+}
+
+#[test]
+fn test_three_players_turn_order() {
+    let mut g = Game::new("X,4,turn_order=XΔO").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("3").unwrap();
+
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.board[0], vec!["X", "Δ", "O", "4"]);
+    assert_eq!(state.next_on_move, "X");
+    assert_eq!(state.players, vec!["X", "Δ", "O"]);
+}
+
+#[test]
+fn test_four_players_first_to_line() {
+    let mut g = Game::new("□,5,players=4").unwrap();
+    // □ completes the first row while the others play elsewhere
+    for board_move in [
+        "1", "6", "11", "16", "2", "7", "12", "17", "3", "8", "13", "18",
+    ] {
+        g.play_move(board_move).unwrap();
+    }
+    for board_move in ["4", "9", "14", "19", "5"] {
+        g.play_move(board_move).unwrap();
+    }
+
+    assert_eq!(
+        g.get_outcome(),
+        Outcome::Win {
+            winner: PlayerMark::Square,
+            reason: WinReason::Line,
+            line: vec![1, 2, 3, 4, 5],
+        }
+    );
+}
+
+#[test]
+fn test_three_players_elimination() {
+    let mut g = Game::new("X,3,players=3,win_rule=Elimination").unwrap();
+    // X completes the first row and is out, O and Δ play on
+    for board_move in ["1", "4", "7", "2", "5", "8", "3"] {
+        g.play_move(board_move).unwrap();
+    }
+    let state = deserialize_game_state(&g.get_state());
+    assert!(!state.finished);
+    assert_eq!(state.eliminated, vec!["X"]);
+    assert_eq!(state.next_on_move, "O");
+
+    // Δ resigns, O is the last player remaining
+    g.resign("t").unwrap();
+    assert_eq!(
+        g.get_outcome(),
+        Outcome::Win {
+            winner: PlayerMark::O,
+            reason: WinReason::Resignation,
+            line: vec![],
+        }
+    );
+    let replayed = g.replay(g.get_moves().len());
+    assert_eq!(replayed.get_state(), g.get_state());
+}
+
+#[test]
+fn test_three_players_resigned_player_is_skipped() {
+    let mut g = Game::new("X,4,players=3").unwrap();
+    g.resign("O").unwrap();
+    g.play_move("1").unwrap();
+
+    let state = deserialize_game_state(&g.get_state());
+    assert!(!state.finished);
+    assert_eq!(state.next_on_move, "Δ");
+    assert!(g.resign("O").is_err());
+}
+
+#[test]
+#[should_panic(expected = "Draws can be offered only in a game of two players")]
+fn test_three_players_no_draw_offer() {
+    let mut g = Game::new("X,4,players=3").unwrap();
+    g.offer_draw("X").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "More than two players can play only Classic without Misere")]
+fn test_three_players_misere() {
+    let _g = Game::new("X,4,players=3,misere=On").unwrap();
+}
+
+#[test]
+fn test_blocked_cells() {
+    let mut g = Game::new("X,3,blocked=5").unwrap();
+    assert_eq!(g.legal_moves().len(), 8);
+    assert_eq!(
+        g.play_move("5").unwrap_err().to_string(),
+        "Cell 5 is blocked"
+    );
+
+    // the diagonal through the blocked center does not count
+    for board_move in ["1", "2", "9", "3", "7", "4", "8"] {
+        g.play_move(board_move).unwrap();
+    }
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.board[1], vec!["O", "#", "6"]);
+    assert_eq!(state.blocked_cells, vec![5]);
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.line, vec![7, 8, 9]);
+}
+
+#[test]
+fn test_blocked_cells_with_gravity() {
+    let mut g = Game::new("X,3,gravity=On,blocked=8").unwrap();
+    // the mark stops on the blocked cell, the cell below can not be reached anymore
+    g.play_move("2").unwrap();
+    g.play_move("2").unwrap();
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.board[0], vec!["1", "O", "3"]);
+    assert_eq!(state.board[1], vec!["4", "X", "6"]);
+    assert_eq!(state.board[2], vec!["7", "#", "9"]);
+    assert_eq!(g.legal_moves(), vec!["1", "3"]);
+}
+
+#[test]
+fn test_wrap_line_across_edge() {
+    let mut g = Game::new("X,4,wrap=On,win_length=3").unwrap();
+    // on the 4x4 torus the first row starts in column 3 and finishes in column 1
+    for board_move in ["3", "6", "4", "7", "1"] {
+        g.play_move(board_move).unwrap();
+    }
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.line, vec![1, 3, 4]);
+
+    // without wrap the same marks are no line
+    let mut g = Game::new("X,4,win_length=3").unwrap();
+    for board_move in ["3", "6", "4", "7", "1"] {
+        g.play_move(board_move).unwrap();
+    }
+    assert!(!g.get_outcome().is_finished());
+}
+
+#[test]
+fn test_swap_after_first_move() {
+    let mut g = Game::new("X,3,swap=On").unwrap();
+    assert!(!deserialize_game_state(&g.get_state()).swap_allowed);
+
+    g.play_move("5").unwrap();
+    assert!(deserialize_game_state(&g.get_state()).swap_allowed);
+    assert_eq!(
+        g.swap("X").unwrap_err().to_string(),
+        "Player X can swap only instead of answering the first move"
+    );
+
+    // the marks stay on the board, O is still on move for the player who moved first
+    g.swap("O").unwrap();
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.moves, vec!["5", "O swaps"]);
+    assert_eq!(state.next_on_move, "O");
+    assert!(!state.swap_allowed);
+    assert!(state.swapped);
+    assert!(g.swap("O").is_err());
+
+    // the swap is replayed for the review
+    for board_move in ["1", "3", "7", "4", "6", "2", "8", "9"] {
+        g.play_move(board_move).unwrap();
+    }
+    assert!(g.get_outcome().is_finished());
+    assert!(g.get_review().is_ok());
+}
+
+#[test]
+fn test_swap_exchanges_clocks() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut g = Game::with_clock("X,3,swap=On,time_control=60", clock.clone()).unwrap();
+    g.start_clock();
+    // the first player uses 10 seconds with X, the second one 25 seconds before swapping
+    clock.advance(Duration::from_secs(10));
+    g.play_move("5").unwrap();
+    clock.advance(Duration::from_secs(25));
+    g.swap("O").unwrap();
+
+    // the first player answers with O and has their own 50 seconds
+    clock.advance(Duration::from_secs(5));
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.next_on_move, "O");
+    assert_eq!(state.remaining_time[0].milliseconds, 35000);
+    assert_eq!(state.remaining_time[1].milliseconds, 45000);
+}
+
+#[test]
+fn test_swap_needs_setting() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("5").unwrap();
+    assert!(!deserialize_game_state(&g.get_state()).swap_allowed);
+    assert_eq!(
+        g.swap("O").unwrap_err().to_string(),
+        "Swap is allowed only with the Swap setting"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Swap can be played only in a game of two players")]
+fn test_swap_three_players() {
+    let _g = Game::new("X,4,players=3,swap=On").unwrap();
+}
+
+#[test]
+fn test_blocked_cells_with_gravity_unreachable_cells() {
+    let mut g = Game::new("X,3,gravity=On,blocked=5").unwrap();
+    // no mark can fall past the center, so cell 8 below it can not be played
+    for column in ["1", "3", "3", "1", "1", "3", "2"] {
+        g.play_move(column).unwrap();
+    }
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.blocked_cells, vec![5, 8]);
+    assert_eq!(state.board[2], vec!["X", "#", "O"]);
+    assert_eq!(state.outcome.status, "Draw");
+    assert_eq!(state.outcome.reason, Some(String::from("BoardFull")));
+    assert!(g.legal_moves().is_empty());
+    assert!(g.get_hint().is_err());
+}
+
+#[test]
+#[should_panic(expected = "Blocked Cells must leave some cells to play")]
+fn test_blocked_cells_with_gravity_no_cell_left() {
+    let _g = Game::new("X,3,gravity=On,blocked=1 2 3").unwrap();
+}
+
+#[test]
+fn test_game_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Game>();
+}
+
+#[test]
+fn test_connect_four_with_win_length() {
+    let mut g = Game::new("X,width=7,height=6,gravity=On,win_length=4").unwrap();
+    // O stacks on the first three marks of X, X completes four on the bottom row
+    for column in ["1", "1", "2", "2", "3", "3"] {
+        g.play_move(column).unwrap();
+    }
+    assert!(!g.get_outcome().is_finished());
+    g.play_move("4").unwrap();
+
+    let state = deserialize_game_state(&g.get_state());
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.line, vec![36, 37, 38, 39]);
+}
+
+#[test]
+fn test_get_winning_lines() {
+    use crate::types::board_dimension::{
+        get_fixed_square, parse as parse_board_dimension, parse_sides,
+    };
+
+    let lines = get_winning_lines(&parse_board_dimension("3").unwrap(), 3);
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines[0], vec![(0, 0), (0, 1), (0, 2)]);
+    assert_eq!(lines[5], vec![(0, 2), (1, 2), (2, 2)]);
+    assert_eq!(lines[7], vec![(0, 2), (1, 1), (2, 0)]);
+
+    assert_eq!(
+        get_winning_lines(&parse_board_dimension("5").unwrap(), 5).len(),
+        12
+    );
+
+    // 4 wide, 3 high: 2 per row, 4 columns, 2 of each diagonal
+    let lines = get_winning_lines(
+        &parse_sides(&parse_board_dimension("3").unwrap(), Some("4"), None).unwrap(),
+        3,
+    );
+    assert_eq!(lines.len(), 14);
+    assert_eq!(lines[1], vec![(0, 1), (0, 2), (0, 3)]);
+    assert_eq!(lines[13], vec![(0, 3), (1, 2), (2, 1)]);
+
+    // five in a row on 6x6: 2 per row and column, 4 of each diagonal
+    assert_eq!(get_winning_lines(&get_fixed_square(6), 5).len(), 32);
+}
+
+#[test]
+fn test_get_wrapped_winning_lines() {
+    use crate::types::board_dimension::{parse as parse_board_dimension, parse_sides};
+
+    // 3 rows, 3 columns and 3 of each diagonal on a 3x3 torus
+    let lines = get_wrapped_winning_lines(&parse_board_dimension("3").unwrap(), 3);
+    assert_eq!(lines.len(), 12);
+    assert_eq!(lines[7], vec![(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(lines[10], vec![(0, 1), (1, 0), (2, 2)]);
+
+    // 4 wide, 3 high: 4 starts per row and diagonal, columns are as high as the line
+    let lines = get_wrapped_winning_lines(
+        &parse_sides(&parse_board_dimension("3").unwrap(), Some("4"), None).unwrap(),
+        3,
+    );
+    assert_eq!(lines.len(), 40);
+    assert_eq!(lines[2], vec![(0, 2), (0, 3), (0, 0)]);
+
+    // three in a row on a 4x4 torus: every cell starts a line in each direction
+    let lines = get_wrapped_winning_lines(&parse_board_dimension("4").unwrap(), 3);
+    assert_eq!(lines.len(), 64);
+    assert_eq!(lines[2], vec![(0, 2), (0, 3), (0, 0)]);
+}
+
+#[test]
+fn test_calculate_result_same_as_scan() {
+    use crate::types::board_dimension::{parse as parse_board_dimension, parse_sides};
+    use crate::types::vec_board::VecBoard;
+
+    for (width, height) in [(3, 3), (4, 4), (5, 5), (4, 3), (3, 5), (7, 6)] {
+        let dimension = parse_sides(
+            &parse_board_dimension("3").unwrap(),
+            Some(&width.to_string()),
+            Some(&height.to_string()),
+        )
+        .unwrap();
+        let cell_count = width * height;
+
+        // Every cell order from a few offsets and steps, marks alternate
+        for step in [1, 2, 3] {
+            for offset in 0..cell_count {
+                let mut board = Board::new(dimension.clone());
+                let mut vec_board = VecBoard::new(dimension.clone());
+                let mut player_mark = PlayerMark::X;
+
+                for i in 0..cell_count {
+                    let cell_idx = (offset + i * step) % cell_count;
+                    let (row_idx, col_idx) = (cell_idx / width, cell_idx % width);
+                    if board.get_cell(row_idx, col_idx).is_some() {
+                        continue;
+                    }
+                    board.set_cell(row_idx, col_idx, Some(player_mark.clone()));
+                    vec_board.set_cell(row_idx, col_idx, Some(player_mark.clone()));
+
+                    let result = calculate_result(&board, false);
+                    assert_eq!(result, calculate_result_by_scan(&vec_board));
+                    if result.is_finished() {
+                        break;
+                    }
+                    player_mark = if player_mark == PlayerMark::X {
+                        PlayerMark::O
+                    } else {
+                        PlayerMark::X
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+proptest! {
+    // Random games on all board shapes, the incremental result must match a full scan
+    #[test]
+    fn test_calculate_result_after_move_same_as_scan(
+        (width, height, cell_order) in (3..=8usize, 3..=8usize).prop_flat_map(|(width, height)| {
+            let cells: Vec<usize> = (0..width * height).collect();
+            (Just(width), Just(height), Just(cells).prop_shuffle())
+        })
+    ) {
+        use crate::types::board_dimension::{parse as parse_board_dimension, parse_sides};
+        use crate::types::vec_board::VecBoard;
+
+        let dimension = parse_sides(
+            &parse_board_dimension("3").unwrap(),
+            Some(&width.to_string()),
+            Some(&height.to_string()),
+        )
+        .unwrap();
+        let mut board = Board::new(dimension.clone());
+        let mut vec_board = VecBoard::new(dimension);
+        let mut player_mark = PlayerMark::X;
+
+        for cell_idx in cell_order {
+            let (row_idx, col_idx) = (cell_idx / width, cell_idx % width);
+            board.set_cell(row_idx, col_idx, Some(player_mark.clone()));
+            vec_board.set_cell(row_idx, col_idx, Some(player_mark.clone()));
+
+            let result = calculate_result_after_move(&board, row_idx, col_idx, false);
+            prop_assert_eq!(&result, &calculate_result_by_scan(&vec_board));
+            if result.is_finished() {
+                break;
+            }
+            player_mark = if player_mark == PlayerMark::X {
+                PlayerMark::O
+            } else {
+                PlayerMark::X
+            };
+        }
+    }
+}
+
+#[test]
+fn test_is_dead_position() {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+
+    let mut board = Board::new(parse_board_dimension("3").unwrap());
+    // X O X
+    // X O O
+    // O . .   O can still complete the middle column
+    for (cell_idx, pm) in [
+        (0, PlayerMark::X),
+        (1, PlayerMark::O),
+        (2, PlayerMark::X),
+        (3, PlayerMark::X),
+        (4, PlayerMark::O),
+        (5, PlayerMark::O),
+        (6, PlayerMark::O),
+    ] {
+        board.set_cell(cell_idx / 3, cell_idx % 3, Some(pm));
+    }
+    let players = [PlayerMark::X, PlayerMark::O];
+    assert!(!is_dead_position(&board, &players));
+    // an eliminated O can not complete it
+    assert!(is_dead_position(&board, &players[..1]));
+
+    // X in the middle of the last row blocks it, two cells stay empty
+    board.set_cell(2, 1, Some(PlayerMark::X));
+    assert!(is_dead_position(&board, &players));
+}
+
+#[test]
+fn test_calculate_result_misere() {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+
+    let mut board = Board::new(parse_board_dimension("3").unwrap());
+    for col_idx in 0..3 {
+        board.set_cell(0, col_idx, Some(PlayerMark::X));
+    }
+
+    assert_eq!(
+        calculate_result_after_move(&board, 0, 2, true),
+        Outcome::Win {
+            winner: PlayerMark::O,
+            reason: WinReason::Misere,
+            line: vec![1, 2, 3],
+        }
+    );
+    assert_eq!(
+        calculate_result(&board, true),
+        calculate_result_after_move(&board, 0, 2, true)
+    );
+}
@@ -1,13 +1,175 @@
+use crate::types::outcome::Outcome;
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct GameState {
     pub board: Vec<Vec<String>>,
     pub next_on_move: String,
     pub finished: bool,
-    pub result: String,
+    pub outcome: OutcomeState,
+    // Mark of the player whose draw offer waits for an answer
+    pub draw_offer: Option<String>,
+    // Board moves and actions like "X offers draw" in the order they happened
+    pub moves: Vec<String>,
+    // Time left for every player, empty without a time control
+    pub remaining_time: Vec<RemainingTime>,
+    // Column numbers a mark can be dropped into, None without gravity
+    pub playable_columns: Option<Vec<u8>>,
+    // Marks the player on move may place, both in wild, empty when finished
+    pub placeable_marks: Vec<String>,
+    // Marks of all players in the order they move
+    pub players: Vec<String>,
+    // Players out of the game, they resigned or completed a line with the elimination rule
+    pub eliminated: Vec<String>,
+    // Numbers of the cells nobody can play, shown as '#' on the board
+    pub blocked_cells: Vec<u8>,
+    // The player on move may swap sides instead of answering the first move
+    pub swap_allowed: bool,
+    // The players swapped sides, the player who moved first plays the other mark now
+    pub swapped: bool,
 }
 
+// State of UltimateGame: small boards in the order of their numbers, every one as rows
+// like in GameState, and the outcome of every small board
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct UltimateGameState {
+    pub boards: Vec<Vec<Vec<String>>>,
+    pub board_outcomes: Vec<OutcomeState>,
+    // Number of the small board the player on move has to play on, None for any
+    pub active_board: Option<u8>,
+    pub next_on_move: String,
+    pub finished: bool,
+    pub outcome: OutcomeState,
+    pub moves: Vec<String>,
+}
+
+// State of QubicGame: layers from the first to the last, every one as rows of cells,
+// empty cells are empty strings. Cell numbers in the outcome line count from 1 through
+// the layers, rows and columns in that order.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct QubicGameState {
+    pub layers: Vec<Vec<Vec<String>>>,
+    pub next_on_move: String,
+    pub finished: bool,
+    pub outcome: OutcomeState,
+    pub moves: Vec<String>,
+}
+
+// State of NotaktoGame: boards in the order of their numbers, every one as rows like
+// in GameState, and for every board if it is dead
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct NotaktoGameState {
+    pub boards: Vec<Vec<Vec<String>>>,
+    pub dead_boards: Vec<bool>,
+    pub next_on_move: String,
+    pub finished: bool,
+    pub outcome: OutcomeState,
+    pub moves: Vec<String>,
+}
+
+// State of OrderChaosGame: the 6x6 board as rows like in GameState, the player on move
+// and the winner are roles, Order or Chaos
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct OrderChaosGameState {
+    pub board: Vec<Vec<String>>,
+    pub next_on_move: String,
+    pub finished: bool,
+    pub outcome: OutcomeState,
+    pub moves: Vec<String>,
+}
+
+// State of SlidingGame: the board as rows like in GameState. Players place their pieces
+// in the Placing phase, then slide them in the Sliding phase.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct SlidingGameState {
+    pub board: Vec<Vec<String>>,
+    pub next_on_move: String,
+    pub phase: String,
+    // Pieces every player still has to place, all zero in the Sliding phase
+    pub pieces_to_place: Vec<PiecesToPlace>,
+    pub finished: bool,
+    pub outcome: OutcomeState,
+    pub moves: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PiecesToPlace {
+    pub mark: String,
+    pub count: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RemainingTime {
+    pub mark: String,
+    pub milliseconds: u64,
+}
+
+// Stable schema: status is InProgress, Win or Draw, winner is set only for Win,
+// line lists the cell numbers of the completed line (empty unless won on the board)
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct OutcomeState {
+    pub status: String,
+    pub winner: Option<String>,
+    pub reason: Option<String>,
+    pub line: Vec<u8>,
+}
+
+impl<W: Display> From<&Outcome<W>> for OutcomeState {
+    fn from(outcome: &Outcome<W>) -> Self {
+        match outcome {
+            Outcome::InProgress => OutcomeState {
+                status: String::from("InProgress"),
+                winner: None,
+                reason: None,
+                line: vec![],
+            },
+            Outcome::Win {
+                winner,
+                reason,
+                line,
+            } => OutcomeState {
+                status: String::from("Win"),
+                winner: Some(winner.to_string()),
+                reason: Some(reason.to_string()),
+                line: line.clone(),
+            },
+            Outcome::Draw { reason } => OutcomeState {
+                status: String::from("Draw"),
+                winner: None,
+                reason: Some(reason.to_string()),
+                line: vec![],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
 pub fn deserialize_game_state(s: &str) -> GameState {
     serde_json::from_str(s).unwrap()
 }
+
+#[cfg(test)]
+pub fn deserialize_ultimate_game_state(s: &str) -> UltimateGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[cfg(test)]
+pub fn deserialize_qubic_game_state(s: &str) -> QubicGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[cfg(test)]
+pub fn deserialize_notakto_game_state(s: &str) -> NotaktoGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[cfg(test)]
+pub fn deserialize_order_chaos_game_state(s: &str) -> OrderChaosGameState {
+    serde_json::from_str(s).unwrap()
+}
+
+#[cfg(test)]
+pub fn deserialize_sliding_game_state(s: &str) -> SlidingGameState {
+    serde_json::from_str(s).unwrap()
+}
@@ -1,14 +1,61 @@
+mod analysis;
 mod game;
 mod game_state;
+mod notakto_game;
+mod order_chaos_game;
+mod players;
+mod qubic_game;
+mod search;
 mod setting_rules;
 mod settings;
+mod sliding_game;
+mod solver;
 mod state_calculation;
+mod timer;
 mod types;
+mod ultimate_game;
 
 // NOTE 08: All communication with the library goes through JSON, to mimic Front to Back End communication
 
 // Main library public type
 pub use game::Game;
+// Game types for variants not played on a single board, same kind of API as Game
+pub use notakto_game::NotaktoGame;
+pub use order_chaos_game::OrderChaosGame;
+pub use qubic_game::QubicGame;
+pub use sliding_game::SlidingGame;
+pub use ultimate_game::UltimateGame;
+
+// Typed result of a game, the same information is in the JSON state
+pub use types::outcome::{DrawReason, Outcome, WinReason};
+pub use types::player_mark::PlayerMark;
+pub use types::role::Role;
+
+// Time source for time controls, a manual clock makes timed games deterministic
+pub use types::clock::{Clock, ManualClock, SystemClock};
+
+// Pluggable players, Front End can add its own implementations
+pub use players::minimax_player::MinimaxPlayer;
+pub use players::random_player::RandomPlayer;
+pub use players::scripted_player::ScriptedPlayer;
+pub use players::Player;
+
+// Perfect play for small boards, can be saved to and loaded from a binary file
+pub use solver::Tablebase;
 
 // JSON representation for Front End to fill controls
 pub use setting_rules::get_game_settings_rules;
+pub use settings::get_variant;
+
+// Internals used by benches/, not part of the supported API
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod benchmarks {
+    pub use crate::state_calculation::{
+        calculate_result, calculate_result_after_move, calculate_result_by_scan,
+    };
+    pub use crate::types::board::{Board, CellGrid};
+    pub use crate::types::board_dimension::parse as parse_board_dimension;
+    pub use crate::types::player_mark::PlayerMark;
+    pub use crate::types::vec_board::VecBoard;
+}
@@ -0,0 +1,246 @@
+// NOTE 20: Public type for Notakto. Both players place X on one or more boards,
+//          a board with a full line is dead and can not be played anymore.
+//          The player who kills the last board loses, so there are no draws.
+
+#[cfg(test)]
+use crate::game_state::deserialize_notakto_game_state;
+use crate::game_state::{NotaktoGameState, OutcomeState};
+use crate::settings::{parse, Settings};
+use crate::state_calculation::calculate_result_after_move;
+use crate::types::board::{Board, CellGrid};
+use crate::types::errors::TicTacToeError;
+use crate::types::notakto_move::parse as parse_notakto_move;
+use crate::types::outcome::{Outcome, WinReason};
+use crate::types::player_mark::PlayerMark;
+use crate::types::variant::Variant;
+use anyhow::bail;
+
+// The only mark played, players are told apart by next_on_move
+const PLACED_MARK: PlayerMark = PlayerMark::X;
+
+#[derive(Debug, Clone)]
+pub struct NotaktoGame {
+    settings: Settings,
+    // Boards in the order of their numbers
+    boards: Vec<Board>,
+    dead_boards: Vec<bool>,
+    next_on_move: PlayerMark,
+    outcome: Outcome,
+    moves: Vec<String>,
+}
+
+impl NotaktoGame {
+    pub fn new(settings_str: &str) -> Result<NotaktoGame, TicTacToeError> {
+        let settings = parse(settings_str)?;
+        if settings.variant != Variant::Notakto {
+            bail!("Variant '{}' is not Notakto", settings.variant);
+        }
+        if settings.early_draw
+            || settings.misere
+            || settings.gravity
+            || settings.time_control.is_some()
+            || !settings.board_dimension.is_square()
+            || settings.turn_order.get_players().len() > 2
+            || !settings.blocked_cells.is_empty()
+            || settings.wrap
+            || settings.swap
+            || settings.win_length.is_some()
+        {
+            bail!("Notakto supports only First Player, Board Size and Boards settings");
+        }
+
+        let board_count = settings.board_count.get_value() as usize;
+        Ok(NotaktoGame {
+            boards: vec![Board::new(settings.board_dimension.clone()); board_count],
+            dead_boards: vec![false; board_count],
+            next_on_move: settings.first_player.clone(),
+            outcome: Outcome::InProgress,
+            moves: vec![],
+            settings,
+        })
+    }
+
+    pub fn play_move(&mut self, move_str: &str) -> Result<(), TicTacToeError> {
+        if self.outcome.is_finished() {
+            bail!("Game is finished, you can not play a move");
+        }
+
+        let notakto_move = parse_notakto_move(
+            &self.settings.board_dimension,
+            &self.settings.board_count,
+            move_str,
+        )?;
+        let board_idx = notakto_move.board_number as usize - 1;
+        let (row_idx, col_idx) = notakto_move.cell.get_indices();
+        let (row_idx, col_idx) = (row_idx as usize, col_idx as usize);
+
+        if self.dead_boards[board_idx] {
+            bail!("Board {} is already dead", board_idx + 1);
+        }
+        if self.boards[board_idx].get_cell(row_idx, col_idx).is_some() {
+            bail!("Cell {} is already filled", move_str.trim());
+        }
+
+        self.boards[board_idx].set_cell(row_idx, col_idx, Some(PLACED_MARK));
+        self.moves.push(notakto_move.to_string());
+
+        // a full board always has a line, so a board ends only by dying
+        let board_outcome =
+            calculate_result_after_move(&self.boards[board_idx], row_idx, col_idx, false);
+        if board_outcome.is_finished() {
+            self.dead_boards[board_idx] = true;
+        }
+        if self.dead_boards.iter().all(|dead| *dead) {
+            self.outcome = Outcome::Win {
+                winner: self.next_on_move.get_opponent(),
+                reason: WinReason::LastBoard,
+                line: vec![],
+            };
+        }
+
+        self.next_on_move = self.next_on_move.get_opponent();
+        Ok(())
+    }
+
+    pub fn legal_moves(&self) -> Vec<String> {
+        if self.outcome.is_finished() {
+            return vec![];
+        }
+
+        let board_dim = self.settings.board_dimension.get_width() as usize;
+
+        let mut moves: Vec<String> = vec![];
+        for (board_idx, board) in self.boards.iter().enumerate() {
+            if self.dead_boards[board_idx] {
+                continue;
+            }
+            for cell_idx in 0..board_dim * board_dim {
+                if board
+                    .get_cell(cell_idx / board_dim, cell_idx % board_dim)
+                    .is_none()
+                {
+                    moves.push(format!("{}-{}", board_idx + 1, cell_idx + 1));
+                }
+            }
+        }
+        moves
+    }
+
+    pub fn get_outcome(&self) -> &Outcome {
+        &self.outcome
+    }
+
+    pub fn get_state(&self) -> String {
+        let board_dim = self.settings.board_dimension.get_width() as usize;
+
+        let boards = self
+            .boards
+            .iter()
+            .map(|board| {
+                (0..board_dim)
+                    .map(|row_idx| {
+                        (0..board_dim)
+                            .map(|col_idx| match board.get_cell(row_idx, col_idx) {
+                                None => (row_idx * board_dim + col_idx + 1).to_string(),
+                                Some(pm) => pm.to_string(),
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let ns = NotaktoGameState {
+            boards,
+            dead_boards: self.dead_boards.clone(),
+            next_on_move: self.next_on_move.to_string(),
+            finished: self.outcome.is_finished(),
+            outcome: OutcomeState::from(&self.outcome),
+            moves: self.moves.clone(),
+        };
+
+        serde_json::to_string(&ns).unwrap()
+    }
+}
+
+#[test]
+fn test_new_state() {
+    let g = NotaktoGame::new("O,3,variant=Notakto,boards=2").unwrap();
+    let state = deserialize_notakto_game_state(&g.get_state());
+
+    assert_eq!(state.boards.len(), 2);
+    assert_eq!(state.boards[1][1], vec!["4", "5", "6"]);
+    assert_eq!(state.dead_boards, vec![false, false]);
+    assert_eq!(state.next_on_move, "O");
+    assert_eq!(g.legal_moves().len(), 18);
+}
+
+#[test]
+fn test_both_players_place_x() {
+    let mut g = NotaktoGame::new("X,3,variant=Notakto").unwrap();
+    g.play_move("1-1").unwrap();
+    g.play_move(" 1 - 5 ").unwrap();
+
+    let state = deserialize_notakto_game_state(&g.get_state());
+    assert_eq!(state.boards[0][0], vec!["X", "2", "3"]);
+    assert_eq!(state.boards[0][1], vec!["4", "X", "6"]);
+    assert_eq!(state.moves, vec!["1-1", "1-5"]);
+    assert_eq!(state.next_on_move, "X");
+}
+
+#[test]
+fn test_killing_last_board_loses() {
+    let mut g = NotaktoGame::new("X,3,variant=Notakto,boards=2").unwrap();
+    // X kills board 1 and later also the last board 2
+    for notakto_move in ["1-1", "1-2", "1-3", "2-1", "2-2", "2-9", "2-3"] {
+        assert!(!g.get_outcome().is_finished());
+        g.play_move(notakto_move).unwrap();
+    }
+
+    let state = deserialize_notakto_game_state(&g.get_state());
+    assert_eq!(state.dead_boards, vec![true, true]);
+    assert_eq!(
+        g.get_outcome(),
+        &Outcome::Win {
+            winner: PlayerMark::O,
+            reason: WinReason::LastBoard,
+            line: vec![],
+        }
+    );
+    assert!(g.legal_moves().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Board 1 is already dead")]
+fn test_move_on_dead_board() {
+    let mut g = NotaktoGame::new("X,3,variant=Notakto,boards=2").unwrap();
+    for notakto_move in ["1-1", "1-2", "1-3", "1-5"] {
+        g.play_move(notakto_move).unwrap();
+    }
+}
+
+#[test]
+#[should_panic(expected = "Notakto supports only First Player, Board Size and Boards settings")]
+fn test_unsupported_settings() {
+    let _g = NotaktoGame::new("X,3,variant=Notakto,misere=On").unwrap();
+}
+
+#[test]
+fn test_random_games_end_with_all_boards_dead() {
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    for seed in 0..20 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut g = NotaktoGame::new("X,3,variant=Notakto,boards=3").unwrap();
+        while let Some(notakto_move) = g.legal_moves().choose(&mut rng) {
+            g.play_move(notakto_move).unwrap();
+        }
+
+        // the player who moved last killed the last board and lost
+        let state = deserialize_notakto_game_state(&g.get_state());
+        assert_eq!(state.dead_boards, vec![true; 3]);
+        assert_eq!(state.outcome.winner, Some(state.next_on_move));
+    }
+}
@@ -0,0 +1,233 @@
+// NOTE 21: Public type for Order and Chaos. On a 6x6 board both players may place
+//          X or O, Order wins with five in a row of either mark and Chaos wins when
+//          the board fills without one. Order always moves first, the winner is a role.
+//          The default Board Size stands for 6x6, so does a Width and Height of 6.
+
+#[cfg(test)]
+use crate::game_state::deserialize_order_chaos_game_state;
+use crate::game_state::{OrderChaosGameState, OutcomeState};
+use crate::settings::parse;
+use crate::state_calculation::calculate_result_after_move;
+use crate::types::board::{Board, CellGrid};
+use crate::types::board_dimension::{get_default as get_default_board_dimension, get_fixed_square};
+use crate::types::board_move::parse as parse_board_move;
+use crate::types::errors::TicTacToeError;
+use crate::types::marked_move::split_mark;
+use crate::types::outcome::{Outcome, WinReason};
+use crate::types::player_mark::{get_default as get_default_player_mark, get_two_player_marks};
+use crate::types::role::Role;
+use crate::types::variant::Variant;
+use anyhow::bail;
+
+const BOARD_SIDE: u8 = 6;
+const LINE_LENGTH: u8 = 5;
+
+#[derive(Debug, Clone)]
+pub struct OrderChaosGame {
+    board: Board,
+    next_on_move: Role,
+    outcome: Outcome<Role>,
+    moves: Vec<String>,
+}
+
+impl OrderChaosGame {
+    pub fn new(settings_str: &str) -> Result<OrderChaosGame, TicTacToeError> {
+        let settings = parse(settings_str)?;
+        if settings.variant != Variant::OrderChaos {
+            bail!("Variant '{}' is not OrderChaos", settings.variant);
+        }
+        if settings.board_dimension != get_default_board_dimension()
+            && settings.board_dimension != get_fixed_square(BOARD_SIDE)
+        {
+            bail!("Order and Chaos is always played on {0}x{0}", BOARD_SIDE);
+        }
+        if settings.first_player != get_default_player_mark() {
+            bail!("Order always moves first, First Player can not be set");
+        }
+        if settings.early_draw
+            || settings.misere
+            || settings.gravity
+            || settings.time_control.is_some()
+            || settings.board_count.get_value() > 1
+            || settings.turn_order.get_players().len() > 2
+            || !settings.blocked_cells.is_empty()
+            || settings.wrap
+            || settings.swap
+            || settings.win_length.is_some()
+        {
+            bail!("Order and Chaos supports no other settings");
+        }
+
+        Ok(OrderChaosGame {
+            board: Board::with_line_length(get_fixed_square(BOARD_SIDE), LINE_LENGTH),
+            next_on_move: Role::Order,
+            outcome: Outcome::InProgress,
+            moves: vec![],
+        })
+    }
+
+    // Cell followed by the mark to place, for example 5O
+    pub fn play_move(&mut self, move_str: &str) -> Result<(), TicTacToeError> {
+        if self.outcome.is_finished() {
+            bail!("Game is finished, you can not play a move");
+        }
+
+        let (cell_str, placed_mark) = split_mark(move_str)?;
+        let board_move = parse_board_move(&self.board.dimension, cell_str)?;
+        let (row_idx, col_idx) = board_move.get_indices();
+        let (row_idx, col_idx) = (row_idx as usize, col_idx as usize);
+
+        if let Some(pm) = self.board.get_cell(row_idx, col_idx) {
+            bail!("Cell {} is already filled with '{}'", cell_str.trim(), pm);
+        }
+
+        self.board
+            .set_cell(row_idx, col_idx, Some(placed_mark.clone()));
+        self.moves
+            .push(format!("{}{}", board_move.get_value(), placed_mark));
+        self.next_on_move = self.next_on_move.get_opponent();
+
+        // a line of either mark is for Order, a full board without one for Chaos
+        self.outcome = match calculate_result_after_move(&self.board, row_idx, col_idx, false) {
+            Outcome::Win { line, .. } => Outcome::Win {
+                winner: Role::Order,
+                reason: WinReason::Line,
+                line,
+            },
+            Outcome::Draw { .. } => Outcome::Win {
+                winner: Role::Chaos,
+                reason: WinReason::BoardFull,
+                line: vec![],
+            },
+            Outcome::InProgress => Outcome::InProgress,
+        };
+
+        Ok(())
+    }
+
+    pub fn legal_moves(&self) -> Vec<String> {
+        if self.outcome.is_finished() {
+            return vec![];
+        }
+
+        let board_dim = BOARD_SIDE as usize;
+
+        let mut moves: Vec<String> = vec![];
+        for cell_idx in 0..board_dim * board_dim {
+            if self
+                .board
+                .get_cell(cell_idx / board_dim, cell_idx % board_dim)
+                .is_none()
+            {
+                for pm in get_two_player_marks().into_iter() {
+                    moves.push(format!("{}{}", cell_idx + 1, pm));
+                }
+            }
+        }
+        moves
+    }
+
+    pub fn get_outcome(&self) -> &Outcome<Role> {
+        &self.outcome
+    }
+
+    pub fn get_state(&self) -> String {
+        let board_dim = BOARD_SIDE as usize;
+
+        let board = (0..board_dim)
+            .map(|row_idx| {
+                (0..board_dim)
+                    .map(|col_idx| match self.board.get_cell(row_idx, col_idx) {
+                        None => (row_idx * board_dim + col_idx + 1).to_string(),
+                        Some(pm) => pm.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let ocs = OrderChaosGameState {
+            board,
+            next_on_move: self.next_on_move.to_string(),
+            finished: self.outcome.is_finished(),
+            outcome: OutcomeState::from(&self.outcome),
+            moves: self.moves.clone(),
+        };
+
+        serde_json::to_string(&ocs).unwrap()
+    }
+}
+
+#[test]
+fn test_new_state() {
+    let g = OrderChaosGame::new("variant=OrderChaos").unwrap();
+    let state = deserialize_order_chaos_game_state(&g.get_state());
+
+    assert_eq!(state.board.len(), 6);
+    assert_eq!(state.board[5], vec!["31", "32", "33", "34", "35", "36"]);
+    assert_eq!(state.next_on_move, "Order");
+    assert_eq!(g.legal_moves().len(), 72);
+}
+
+#[test]
+fn test_order_wins_with_five_of_either_mark() {
+    let mut g = OrderChaosGame::new("variant=OrderChaos").unwrap();
+    // Chaos places O marks too, they help Order on the second row
+    for board_move in ["8O", "1X", "9O", "2X", "10O", "3X", "11O", "4X"] {
+        g.play_move(board_move).unwrap();
+    }
+    assert!(!g.get_outcome().is_finished());
+    g.play_move("12o").unwrap();
+
+    let state = deserialize_order_chaos_game_state(&g.get_state());
+    assert_eq!(state.outcome.winner, Some(String::from("Order")));
+    assert_eq!(state.outcome.line, vec![8, 9, 10, 11, 12]);
+    assert_eq!(state.moves[0], "8O");
+}
+
+#[test]
+fn test_chaos_wins_on_full_board() {
+    let mut g = OrderChaosGame::new("variant=OrderChaos").unwrap();
+    // two X and two O in turn, shifted by two columns per row, never five in a row
+    for cell_idx in 0..36 {
+        let mark = match (cell_idx / 6 + 2 * (cell_idx % 6)) % 4 < 2 {
+            true => "X",
+            false => "O",
+        };
+        assert!(!g.get_outcome().is_finished());
+        g.play_move(&format!("{}{}", cell_idx + 1, mark)).unwrap();
+    }
+
+    assert_eq!(
+        g.get_outcome(),
+        &Outcome::Win {
+            winner: Role::Chaos,
+            reason: WinReason::BoardFull,
+            line: vec![],
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Order and Chaos is always played on 6x6")]
+fn test_other_board_size() {
+    let _g = OrderChaosGame::new("X,4,variant=OrderChaos").unwrap();
+}
+
+#[test]
+fn test_board_size_given_as_6x6() {
+    let g = OrderChaosGame::new("variant=OrderChaos,width=6,height=6").unwrap();
+    assert_eq!(g.legal_moves().len(), 72);
+}
+
+#[test]
+#[should_panic(expected = "Order always moves first, First Player can not be set")]
+fn test_other_first_player() {
+    let _g = OrderChaosGame::new("O,variant=OrderChaos").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Move '5' must end with the mark to place, for example 5O")]
+fn test_move_without_mark() {
+    let mut g = OrderChaosGame::new("variant=OrderChaos").unwrap();
+    g.play_move("5").unwrap();
+}
@@ -0,0 +1,108 @@
+use crate::game::Game;
+use crate::players::Player;
+use crate::search::{get_default_depth, score_moves, score_moves_within};
+#[cfg(test)]
+use crate::types::outcome::{DrawReason, Outcome};
+
+pub struct MinimaxPlayer {
+    depth: Option<u8>,
+}
+
+impl MinimaxPlayer {
+    // None searches as deep as the board size allows in reasonable time, or looks the
+    // moves up when the game has a tablebase. With a time control the search also
+    // stops when half of the time budget is used.
+    pub fn new(depth: Option<u8>) -> MinimaxPlayer {
+        MinimaxPlayer { depth }
+    }
+}
+
+impl Player for MinimaxPlayer {
+    fn choose_move(&mut self, game: &Game) -> String {
+        let table_scores = match self.depth {
+            Some(_) => None,
+            None => game
+                .get_tablebase()
+                .and_then(|tablebase| tablebase.score_moves(game)),
+        };
+
+        let scores = match table_scores {
+            Some(scores) => scores,
+            None => {
+                let depth = self.depth.unwrap_or_else(|| get_default_depth(game));
+                match game.get_time_budget() {
+                    Some(budget) => score_moves_within(game, depth, budget / 2),
+                    None => score_moves(game, depth),
+                }
+            }
+        };
+
+        let mut best: Option<(String, i32)> = None;
+        for (board_move, score) in scores {
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_score)| score > *best_score)
+            {
+                best = Some((board_move, score));
+            }
+        }
+
+        best.map(|(board_move, _)| board_move).unwrap_or_default()
+    }
+}
+
+#[test]
+fn test_choose_move_takes_win() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("4").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+
+    assert_eq!(MinimaxPlayer::new(None).choose_move(&g), "3");
+}
+
+#[test]
+fn test_choose_move_blocks_loss() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("2").unwrap();
+
+    assert_eq!(MinimaxPlayer::new(None).choose_move(&g), "3");
+}
+
+#[test]
+fn test_minimax_against_itself_is_tied() {
+    let mut g = Game::new("X,3").unwrap();
+    let mut p = MinimaxPlayer::new(None);
+
+    while !g.is_finished() {
+        let board_move = p.choose_move(&g);
+        g.play_move(&board_move).unwrap();
+    }
+
+    assert_eq!(
+        g.get_outcome(),
+        Outcome::Draw {
+            reason: DrawReason::BoardFull
+        }
+    );
+}
+
+#[test]
+fn test_choose_move_with_time_control() {
+    use crate::types::clock::ManualClock;
+    use std::sync::Arc;
+
+    // the manual clock does not run, O always has the full second
+    let mut g = Game::with_clock("X,4,time_control=1/move", Arc::new(ManualClock::new())).unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("6").unwrap();
+    g.play_move("3").unwrap();
+
+    // O has to block the first row even with little time
+    assert_eq!(MinimaxPlayer::new(None).choose_move(&g), "4");
+}
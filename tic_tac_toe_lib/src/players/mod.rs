@@ -0,0 +1,18 @@
+// NOTE 09: Trait object as an extension point. Front End drives two Box<dyn Player>
+//          and never needs to know whether a human or a bot is behind the move.
+
+use crate::game::Game;
+
+pub mod minimax_player;
+pub mod random_player;
+pub mod scripted_player;
+
+pub trait Player {
+    // Move in the same notation a human would type, for example "5"
+    fn choose_move(&mut self, game: &Game) -> String;
+
+    // Interactive players are asked again when their move is rejected
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,63 @@
+use crate::game::Game;
+use crate::players::Player;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+pub struct RandomPlayer {
+    rng: StdRng,
+}
+
+impl RandomPlayer {
+    pub fn new() -> RandomPlayer {
+        RandomPlayer {
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    // Same seed gives the same sequence of moves, useful for tests
+    pub fn with_seed(seed: u64) -> RandomPlayer {
+        RandomPlayer {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Default for RandomPlayer {
+    fn default() -> Self {
+        RandomPlayer::new()
+    }
+}
+
+impl Player for RandomPlayer {
+    fn choose_move(&mut self, game: &Game) -> String {
+        game.legal_moves()
+            .choose(&mut self.rng)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[test]
+fn test_choose_move_is_legal() {
+    let mut g = Game::new("X,4").unwrap();
+    let mut p = RandomPlayer::with_seed(7);
+
+    for _ in 0..16 {
+        if g.legal_moves().is_empty() {
+            break;
+        }
+        let board_move = p.choose_move(&g);
+        assert!(g.legal_moves().contains(&board_move));
+        g.play_move(&board_move).unwrap();
+    }
+}
+
+#[test]
+fn test_choose_move_same_seed_same_move() {
+    let g = Game::new("X,5").unwrap();
+    let mut p1 = RandomPlayer::with_seed(42);
+    let mut p2 = RandomPlayer::with_seed(42);
+
+    assert_eq!(p1.choose_move(&g), p2.choose_move(&g));
+}
@@ -0,0 +1,32 @@
+use crate::game::Game;
+use crate::players::Player;
+use std::collections::VecDeque;
+
+// Replays a fixed list of moves, returns an empty move once the list is exhausted
+pub struct ScriptedPlayer {
+    moves: VecDeque<String>,
+}
+
+impl ScriptedPlayer {
+    pub fn new(moves: Vec<String>) -> ScriptedPlayer {
+        ScriptedPlayer {
+            moves: moves.into(),
+        }
+    }
+}
+
+impl Player for ScriptedPlayer {
+    fn choose_move(&mut self, _game: &Game) -> String {
+        self.moves.pop_front().unwrap_or_default()
+    }
+}
+
+#[test]
+fn test_choose_move_replays_in_order() {
+    let g = Game::new("X,3").unwrap();
+    let mut p = ScriptedPlayer::new(vec![String::from("5"), String::from("1")]);
+
+    assert_eq!(p.choose_move(&g), "5");
+    assert_eq!(p.choose_move(&g), "1");
+    assert_eq!(p.choose_move(&g), "");
+}
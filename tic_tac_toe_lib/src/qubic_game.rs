@@ -0,0 +1,288 @@
+// NOTE 19: Public type for 3D tic-tac-toe on a cube of 3x3x3 or 4x4x4 cells (Qubic).
+//          Lines run along rows, columns and pillars, across the diagonals of every
+//          plane and through the four space diagonals. Cells are kept as bitboards,
+//          cell index is layer * n^2 + row * n + column.
+
+#[cfg(test)]
+use crate::game_state::deserialize_qubic_game_state;
+use crate::game_state::{OutcomeState, QubicGameState};
+use crate::settings::{parse, Settings};
+use crate::types::errors::TicTacToeError;
+use crate::types::outcome::{DrawReason, Outcome, WinReason};
+use crate::types::player_mark::{get_two_player_marks, PlayerMark};
+use crate::types::qubic_move::parse as parse_qubic_move;
+use crate::types::variant::Variant;
+use anyhow::bail;
+
+// 5x5x5 does not fit into a u64 bitboard
+const MAX_CUBE_DIMENSION: u8 = 4;
+
+#[derive(Debug, Clone)]
+pub struct QubicGame {
+    settings: Settings,
+    // Indexed by PlayerMark
+    marks: [u64; 2],
+    lines: Vec<u64>,
+    next_on_move: PlayerMark,
+    outcome: Outcome,
+    moves: Vec<String>,
+}
+
+impl QubicGame {
+    pub fn new(settings_str: &str) -> Result<QubicGame, TicTacToeError> {
+        let settings = parse(settings_str)?;
+        if settings.variant != Variant::Qubic {
+            bail!("Variant '{}' is not Qubic", settings.variant);
+        }
+        if settings.board_dimension.get_width() > MAX_CUBE_DIMENSION {
+            bail!(
+                "Qubic Board Dimension can not be greater than {}",
+                MAX_CUBE_DIMENSION
+            );
+        }
+        if settings.early_draw
+            || settings.misere
+            || settings.gravity
+            || settings.time_control.is_some()
+            || !settings.board_dimension.is_square()
+            || settings.board_count.get_value() > 1
+            || settings.turn_order.get_players().len() > 2
+            || !settings.blocked_cells.is_empty()
+            || settings.wrap
+            || settings.swap
+            || settings.win_length.is_some()
+        {
+            bail!("Qubic supports only First Player and Board Size settings");
+        }
+
+        Ok(QubicGame {
+            marks: [0; 2],
+            lines: get_cube_lines(settings.board_dimension.get_width()),
+            next_on_move: settings.first_player.clone(),
+            outcome: Outcome::InProgress,
+            moves: vec![],
+            settings,
+        })
+    }
+
+    pub fn play_move(&mut self, move_str: &str) -> Result<(), TicTacToeError> {
+        if self.outcome.is_finished() {
+            bail!("Game is finished, you can not play a move");
+        }
+
+        let qubic_move = parse_qubic_move(&self.settings.board_dimension, move_str)?;
+        let cell_idx = self.get_cell_idx(qubic_move.get_indices());
+        if let Some(pm) = self.get_cell(cell_idx) {
+            bail!("Cell {} is already filled with '{}'", qubic_move, pm);
+        }
+
+        let bit = 1u64 << cell_idx;
+        self.marks[self.next_on_move.get_index()] |= bit;
+        self.moves.push(qubic_move.to_string());
+
+        // only lines through the cell just played can have been completed
+        let marks = self.marks[self.next_on_move.get_index()];
+        if let Some(line) = self
+            .lines
+            .iter()
+            .find(|line| *line & bit != 0 && marks & *line == **line)
+        {
+            self.outcome = Outcome::Win {
+                winner: self.next_on_move.clone(),
+                reason: WinReason::Line,
+                line: get_line_cell_numbers(*line),
+            };
+        } else if (self.marks[0] | self.marks[1]).count_ones() == self.get_cell_count() {
+            self.outcome = Outcome::Draw {
+                reason: DrawReason::BoardFull,
+            };
+        }
+
+        self.next_on_move = self.next_on_move.get_opponent();
+
+        Ok(())
+    }
+
+    pub fn legal_moves(&self) -> Vec<String> {
+        if self.outcome.is_finished() {
+            return vec![];
+        }
+
+        let cube_dim = self.settings.board_dimension.get_width() as u32;
+        (0..self.get_cell_count())
+            .filter(|cell_idx| self.get_cell(*cell_idx).is_none())
+            .map(|cell_idx| {
+                format!(
+                    "{}-{}-{}",
+                    cell_idx / (cube_dim * cube_dim) + 1,
+                    cell_idx / cube_dim % cube_dim + 1,
+                    cell_idx % cube_dim + 1
+                )
+            })
+            .collect()
+    }
+
+    pub fn get_outcome(&self) -> &Outcome {
+        &self.outcome
+    }
+
+    pub fn get_state(&self) -> String {
+        let cube_dim = self.settings.board_dimension.get_width();
+
+        let layers = (0..cube_dim)
+            .map(|layer_idx| {
+                (0..cube_dim)
+                    .map(|row_idx| {
+                        (0..cube_dim)
+                            .map(|col_idx| {
+                                let cell_idx = self.get_cell_idx((layer_idx, row_idx, col_idx));
+                                match self.get_cell(cell_idx) {
+                                    None => String::from(""),
+                                    Some(pm) => pm.to_string(),
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let qs = QubicGameState {
+            layers,
+            next_on_move: self.next_on_move.to_string(),
+            finished: self.outcome.is_finished(),
+            outcome: OutcomeState::from(&self.outcome),
+            moves: self.moves.clone(),
+        };
+
+        serde_json::to_string(&qs).unwrap()
+    }
+
+    fn get_cell_count(&self) -> u32 {
+        (self.settings.board_dimension.get_width() as u32).pow(3)
+    }
+
+    fn get_cell_idx(&self, (layer_idx, row_idx, col_idx): (u8, u8, u8)) -> u32 {
+        let cube_dim = self.settings.board_dimension.get_width() as u32;
+        (layer_idx as u32 * cube_dim + row_idx as u32) * cube_dim + col_idx as u32
+    }
+
+    fn get_cell(&self, cell_idx: u32) -> Option<PlayerMark> {
+        get_two_player_marks()
+            .into_iter()
+            .find(|pm| self.marks[pm.get_index()] & (1 << cell_idx) != 0)
+    }
+}
+
+// Every direction is taken once, a line is kept when all its cells are in the cube
+fn get_cube_lines(cube_dim: u8) -> Vec<u64> {
+    let n = cube_dim as i32;
+    let mut lines: Vec<u64> = vec![];
+
+    for direction in get_directions() {
+        for start in 0..n.pow(3) {
+            let (layer, row, col) = (start / (n * n), start / n % n, start % n);
+            let end = (
+                layer + direction.0 * (n - 1),
+                row + direction.1 * (n - 1),
+                col + direction.2 * (n - 1),
+            );
+            if [end.0, end.1, end.2].iter().any(|v| *v < 0 || *v >= n) {
+                continue;
+            }
+
+            let line = (0..n).fold(0u64, |line, step| {
+                let cell_idx = ((layer + direction.0 * step) * n + row + direction.1 * step) * n
+                    + col
+                    + direction.2 * step;
+                line | 1 << cell_idx
+            });
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+// 13 directions, the opposite ones would give the same lines
+fn get_directions() -> Vec<(i32, i32, i32)> {
+    let mut directions: Vec<(i32, i32, i32)> = vec![];
+    for layer in -1..=1 {
+        for row in -1..=1 {
+            for col in -1..=1 {
+                let first_non_zero = [layer, row, col].into_iter().find(|v| *v != 0);
+                if first_non_zero == Some(1) {
+                    directions.push((layer, row, col));
+                }
+            }
+        }
+    }
+    directions
+}
+
+// Cell numbers count from 1 in the order of cell indices
+fn get_line_cell_numbers(line: u64) -> Vec<u8> {
+    (0..u64::BITS as u8)
+        .filter(|bit| line & (1 << bit) != 0)
+        .map(|bit| bit + 1)
+        .collect()
+}
+
+#[test]
+fn test_get_cube_lines() {
+    assert_eq!(get_directions().len(), 13);
+    assert_eq!(get_cube_lines(3).len(), 49);
+    assert_eq!(get_cube_lines(4).len(), 76);
+}
+
+#[test]
+fn test_new_state() {
+    let g = QubicGame::new("X,4,variant=Qubic").unwrap();
+    let state = deserialize_qubic_game_state(&g.get_state());
+
+    assert_eq!(state.layers.len(), 4);
+    assert_eq!(state.layers[0][0], vec!["", "", "", ""]);
+    assert_eq!(g.legal_moves().len(), 64);
+    assert_eq!(g.legal_moves()[5], "1-2-2");
+}
+
+#[test]
+fn test_detect_space_diagonal() {
+    let mut g = QubicGame::new("X,3,variant=Qubic").unwrap();
+    for qubic_move in ["1-1-1", "1-1-2", "2-2-2", "1-1-3", "3-3-3"] {
+        g.play_move(qubic_move).unwrap();
+    }
+
+    let state = deserialize_qubic_game_state(&g.get_state());
+    assert!(state.finished);
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.line, vec![1, 14, 27]);
+    assert_eq!(state.layers[1][1], vec!["", "X", ""]);
+}
+
+#[test]
+fn test_detect_pillar() {
+    let mut g = QubicGame::new("O,4,variant=Qubic").unwrap();
+    for qubic_move in [
+        "1-2-3", "1-1-1", "2-2-3", "1-1-2", "3-2-3", "1-1-3", "4-2-3",
+    ] {
+        g.play_move(qubic_move).unwrap();
+    }
+
+    let state = deserialize_qubic_game_state(&g.get_state());
+    assert_eq!(state.outcome.winner, Some(String::from("O")));
+    assert_eq!(state.outcome.line, vec![7, 23, 39, 55]);
+}
+
+#[test]
+#[should_panic(expected = "Qubic Board Dimension can not be greater than 4")]
+fn test_new_too_big() {
+    let _g = QubicGame::new("X,5,variant=Qubic").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Cell 2-2-2 is already filled with 'X'")]
+fn test_play_move_filled() {
+    let mut g = QubicGame::new("X,3,variant=Qubic").unwrap();
+    g.play_move("2-2-2").unwrap();
+    g.play_move("2 - 2 - 2").unwrap();
+}
@@ -0,0 +1,321 @@
+// NOTE 10: Negamax search with alpha-beta pruning. It works on cloned Game values,
+//          so every rule enforced by Game::play_move is respected automatically.
+//          With more than two players the search is paranoid: the player on move at
+//          the root plays against all others, which are searched as one side.
+
+use crate::game::Game;
+use crate::types::player_mark::PlayerMark;
+use crate::types::variant::Variant;
+use std::cmp::max;
+use std::time::{Duration, Instant};
+
+pub const WIN_SCORE: i32 = 100_000;
+const INFINITY: i32 = WIN_SCORE + 1;
+// Scores closer to WIN_SCORE than this can only come from a finished game
+const DECISIVE_MARGIN: i32 = 1_000;
+
+// 3x3 is searched to the end, bigger boards get a depth-limited estimate
+pub fn get_default_depth(game: &Game) -> u8 {
+    match game.get_board().dimension.get_cell_count() {
+        0..=9 => 9,
+        10..=16 => 4,
+        _ => 3,
+    }
+}
+
+pub fn is_decisive(score: i32) -> bool {
+    score.abs() > WIN_SCORE - DECISIVE_MARGIN
+}
+
+// Number of moves, counting both players, until a decisive score ends the game
+pub fn get_moves_to_end(score: i32) -> u32 {
+    (WIN_SCORE - score.abs()) as u32
+}
+
+// Score of every legal move, from the point of view of the player on move
+pub fn score_moves(game: &Game, depth: u8) -> Vec<(String, i32)> {
+    let game = game.without_timer();
+    let root = game.get_next_on_move();
+    game.legal_moves()
+        .into_iter()
+        .map(|board_move| {
+            let mut child = game.clone();
+            child.play_move(&board_move).unwrap();
+            let depth = depth.saturating_sub(1);
+            let score = get_child_score(&game, &child, depth, -INFINITY, INFINITY, 1, root);
+            (board_move, score)
+        })
+        .collect()
+}
+
+// Iterative deepening: a deeper search is started only while it is expected to fit
+// into the budget. Scores of the deepest finished search are returned.
+pub fn score_moves_within(game: &Game, max_depth: u8, budget: Duration) -> Vec<(String, i32)> {
+    let started = Instant::now();
+    // every level is at least this many times slower than the previous one
+    let branching = game.legal_moves().len().max(1) as u32;
+
+    let mut depth = 1;
+    let mut scores = score_moves(game, depth);
+    let mut last_duration = started.elapsed();
+    while depth < max_depth && started.elapsed() + last_duration * branching <= budget {
+        let iteration_started = Instant::now();
+        depth += 1;
+        scores = score_moves(game, depth);
+        last_duration = iteration_started.elapsed();
+    }
+    scores
+}
+
+// Score from the point of view of the side on move, the root player or the others
+fn negamax(game: &Game, depth: u8, mut alpha: i32, beta: i32, ply: i32, root: &PlayerMark) -> i32 {
+    if game.is_finished() {
+        return get_side_terminal_score(game, ply, root);
+    }
+    if depth == 0 {
+        return get_heuristic_score(game, root);
+    }
+
+    let mut best = -INFINITY;
+    for board_move in game.legal_moves() {
+        let mut child = game.clone();
+        child.play_move(&board_move).unwrap();
+
+        let score = get_child_score(game, &child, depth - 1, alpha, beta, ply + 1, root);
+        best = max(best, score);
+        alpha = max(alpha, score);
+        if alpha >= beta {
+            break;
+        }
+    }
+    best
+}
+
+// The sign flips only when the move passes to the other side
+fn get_child_score(
+    parent: &Game,
+    child: &Game,
+    depth: u8,
+    alpha: i32,
+    beta: i32,
+    ply: i32,
+    root: &PlayerMark,
+) -> i32 {
+    if is_same_side(parent.get_next_on_move(), child.get_next_on_move(), root) {
+        negamax(child, depth, alpha, beta, ply, root)
+    } else {
+        -negamax(child, depth, -beta, -alpha, ply, root)
+    }
+}
+
+// In a game of two players the sides are the players themselves
+fn is_same_side(a: &PlayerMark, b: &PlayerMark, root: &PlayerMark) -> bool {
+    (a == root) == (b == root)
+}
+
+// Faster wins and slower losses are preferred
+pub fn get_terminal_score(game: &Game, ply: i32) -> i32 {
+    get_side_terminal_score(game, ply, game.get_next_on_move())
+}
+
+fn get_side_terminal_score(game: &Game, ply: i32, root: &PlayerMark) -> i32 {
+    let outcome = game.get_outcome();
+    let winner = match outcome.get_winner() {
+        Some(winner) => winner,
+        None => return 0,
+    };
+
+    if is_same_side(winner, game.get_next_on_move(), root) {
+        WIN_SCORE - ply
+    } else {
+        -(WIN_SCORE - ply)
+    }
+}
+
+// Lines still open for only one side count in its favour,
+// with the misere rule they count against it.
+fn get_heuristic_score(game: &Game, root: &PlayerMark) -> i32 {
+    if game.get_settings().variant == Variant::Wild {
+        return get_wild_heuristic_score(game);
+    }
+
+    let board = game.get_board();
+    let on_move = game.get_next_on_move();
+    let own_marks = game
+        .get_settings()
+        .turn_order
+        .get_players()
+        .iter()
+        .filter(|pm| is_same_side(pm, on_move, root))
+        .fold(0, |all, pm| all | board.get_marks(pm));
+    let opponent_marks = board.get_occupied() & !own_marks;
+
+    let mut score = 0;
+    for mask in board.get_win_masks() {
+        let own = (own_marks & mask).count_ones() as i32;
+        let opponent = (opponent_marks & mask).count_ones() as i32;
+        if opponent == 0 {
+            score += own * own;
+        }
+        if own == 0 {
+            score -= opponent * opponent;
+        }
+    }
+
+    if game.get_settings().misere {
+        -score
+    } else {
+        score
+    }
+}
+
+// In wild a line of one mark can be completed by either player. Taking turns in it,
+// the player on move places its last mark when an odd number of cells is left.
+fn get_wild_heuristic_score(game: &Game) -> i32 {
+    let board = game.get_board();
+    let x_marks = board.get_marks(&PlayerMark::X);
+    let o_marks = board.get_marks(&PlayerMark::O);
+
+    let mut score = 0;
+    for mask in board.get_win_masks() {
+        let x = (x_marks & mask).count_ones() as i32;
+        let o = (o_marks & mask).count_ones() as i32;
+        if x > 0 && o > 0 {
+            continue;
+        }
+        let filled = x + o;
+        let left = mask.count_ones() as i32 - filled;
+        if left % 2 == 1 {
+            score += filled * filled;
+        } else {
+            score -= filled * filled;
+        }
+    }
+
+    if game.get_settings().misere {
+        -score
+    } else {
+        score
+    }
+}
+
+#[test]
+fn test_score_moves_empty_board_is_draw() {
+    let g = Game::new("X,3").unwrap();
+    let scores = score_moves(&g, 9);
+    assert_eq!(scores.len(), 9);
+    assert!(scores.iter().all(|(_, score)| *score == 0));
+}
+
+#[test]
+fn test_score_moves_finds_immediate_win() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("4").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+
+    let scores = score_moves(&g, 9);
+    let (board_move, score) = scores.iter().max_by_key(|(_, score)| *score).unwrap();
+    assert_eq!(board_move, "3");
+    assert_eq!(*score, WIN_SCORE - 1);
+}
+
+#[test]
+fn test_score_moves_detects_forced_loss() {
+    let mut g = Game::new("X,3").unwrap();
+    // X threatens both 3 (diagonal) and 4 (column), O can block only one
+    g.play_move("1").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("9").unwrap();
+    g.play_move("7").unwrap();
+
+    let scores = score_moves(&g, 9);
+    assert!(scores.iter().all(|(_, score)| *score < 0));
+}
+
+#[test]
+fn test_score_moves_within_budget() {
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("5").unwrap();
+
+    assert_eq!(
+        score_moves_within(&g, 7, Duration::ZERO),
+        score_moves(&g, 1)
+    );
+    assert_eq!(
+        score_moves_within(&g, 7, Duration::from_secs(3600)),
+        score_moves(&g, 7)
+    );
+}
+
+#[test]
+fn test_score_moves_misere_avoids_line() {
+    let mut g = Game::new("X,3,misere=On").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("9").unwrap();
+
+    // X completing the first row loses at once
+    let scores = score_moves(&g, 9);
+    let (_, score) = scores.iter().find(|(m, _)| m == "3").unwrap();
+    assert_eq!(*score, -(WIN_SCORE - 1));
+}
+
+#[test]
+fn test_score_moves_wild_completes_any_line() {
+    let mut g = Game::new("X,3,variant=Wild").unwrap();
+    g.play_move("1O").unwrap();
+    g.play_move("9X").unwrap();
+    g.play_move("2O").unwrap();
+
+    // player O completes the top row of O marks that X started
+    let scores = score_moves(&g, 1);
+    let (best_move, best_score) = scores.iter().max_by_key(|(_, score)| *score).unwrap();
+    assert_eq!(best_move, "3O");
+    assert!(is_decisive(*best_score));
+}
+
+#[test]
+fn test_heuristic_score_wild_counts_lines() {
+    let mut g = Game::new("X,4,variant=Wild").unwrap();
+    g.play_move("1X").unwrap();
+    g.play_move("2X").unwrap();
+
+    // two columns and the diagonal have 3 cells left, the top row only 2
+    assert_eq!(get_heuristic_score(&g, &PlayerMark::X), 3 - 4);
+}
+
+#[test]
+fn test_score_moves_three_players_blocks_next_player() {
+    let mut g = Game::new("X,3,players=3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("9").unwrap();
+    g.play_move("2").unwrap();
+
+    // O must block the first row, Δ moving in between is not trusted to do it
+    let scores = score_moves(&g, 3);
+    let (best_move, _) = scores.iter().max_by_key(|(_, score)| *score).unwrap();
+    assert_eq!(best_move, "3");
+    let (_, score) = scores.iter().find(|(m, _)| m == "4").unwrap();
+    assert_eq!(*score, -(WIN_SCORE - 3));
+}
+
+#[test]
+fn test_score_moves_wrap_finds_win_across_edge() {
+    let mut g = Game::new("X,4,wrap=On,win_length=3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("6").unwrap();
+    g.play_move("4").unwrap();
+    g.play_move("11").unwrap();
+
+    // X on the first and the last column of the first row wins only across the edge
+    let scores = score_moves(&g, 1);
+    let (best_move, best_score) = scores.iter().max_by_key(|(_, score)| *score).unwrap();
+    assert!(best_move == "2" || best_move == "3");
+    assert_eq!(*best_score, WIN_SCORE - 1);
+}
@@ -1,14 +1,49 @@
+use crate::settings::{
+    KEY_ADJACENT, KEY_BLOCKED, KEY_BOARDS, KEY_BOARD_SIZE, KEY_EARLY_DRAW, KEY_FIRST_PLAYER,
+    KEY_GRAVITY, KEY_HEIGHT, KEY_MISERE, KEY_PIECES, KEY_PLAYERS, KEY_SWAP, KEY_TIME_CONTROL,
+    KEY_TURN_ORDER, KEY_VARIANT, KEY_WIDTH, KEY_WIN_LENGTH, KEY_WIN_RULE, KEY_WRAP,
+};
+use crate::types::blocked_cells::{
+    get_all_as_vec_str as get_all_blocked_cells, get_default_as_str as get_default_blocked_cells,
+};
+use crate::types::board_count::{
+    get_all_as_vec_str as get_all_board_counts, get_default as get_default_board_count,
+};
 use crate::types::board_dimension::{
-    get_all_as_vec_str as get_all_board_dimensions, get_default as get_default_board_dimension,
+    get_all_as_vec_str as get_all_board_dimensions, get_all_sides_as_vec_str as get_all_sides,
+    get_default as get_default_board_dimension, get_default_side_as_str as get_default_side,
+};
+use crate::types::piece_count::{
+    get_all_as_vec_str as get_all_piece_counts, get_default_as_str as get_default_piece_count,
+};
+use crate::types::player_count::{
+    get_all_as_vec_str as get_all_player_counts, get_default as get_default_player_count,
 };
 use crate::types::player_mark::{
     get_all_as_vec_str as get_all_player_marks, get_default as get_default_player_mark,
 };
+use crate::types::time_control::{
+    get_all_as_vec_str as get_all_time_controls, get_default_as_str as get_default_time_control,
+};
+use crate::types::toggle::{
+    get_all_as_vec_str as get_all_toggles, get_default as get_default_toggle,
+};
+use crate::types::turn_order::get_allowed_values_as_str as get_turn_order_allowed_values;
+use crate::types::variant::{
+    get_all_as_vec_str as get_all_variants, get_default as get_default_variant,
+};
+use crate::types::win_length::{
+    get_all_as_vec_str as get_all_win_lengths, get_default_as_str as get_default_win_length,
+};
+use crate::types::win_rule::{
+    get_all_as_vec_str as get_all_win_rules, get_default as get_default_win_rule,
+};
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
 pub struct SettingRule {
     pub name: String,
+    pub key: String,
     pub allowed_values: String,
     pub default_value: String,
 }
@@ -18,13 +53,117 @@ pub fn get_game_settings_rules() -> String {
     let rules = vec![
         SettingRule {
             name: String::from("First Player"),
+            key: String::from(KEY_FIRST_PLAYER),
             allowed_values: get_all_player_marks().join(","),
             default_value: get_default_player_mark().to_string(),
         },
         SettingRule {
             name: String::from("Board Size"),
+            key: String::from(KEY_BOARD_SIZE),
             allowed_values: get_all_board_dimensions().join(","),
-            default_value: get_default_board_dimension().get_value().to_string(),
+            default_value: get_default_board_dimension().get_width().to_string(),
+        },
+        SettingRule {
+            name: String::from("Width"),
+            key: String::from(KEY_WIDTH),
+            allowed_values: get_all_sides().join(","),
+            default_value: get_default_side(),
+        },
+        SettingRule {
+            name: String::from("Height"),
+            key: String::from(KEY_HEIGHT),
+            allowed_values: get_all_sides().join(","),
+            default_value: get_default_side(),
+        },
+        SettingRule {
+            name: String::from("Early Draw"),
+            key: String::from(KEY_EARLY_DRAW),
+            allowed_values: get_all_toggles().join(","),
+            default_value: get_default_toggle().to_string(),
+        },
+        SettingRule {
+            name: String::from("Time Control"),
+            key: String::from(KEY_TIME_CONTROL),
+            allowed_values: get_all_time_controls().join(","),
+            default_value: get_default_time_control(),
+        },
+        SettingRule {
+            name: String::from("Misere"),
+            key: String::from(KEY_MISERE),
+            allowed_values: get_all_toggles().join(","),
+            default_value: get_default_toggle().to_string(),
+        },
+        SettingRule {
+            name: String::from("Variant"),
+            key: String::from(KEY_VARIANT),
+            allowed_values: get_all_variants().join(","),
+            default_value: get_default_variant().to_string(),
+        },
+        SettingRule {
+            name: String::from("Gravity"),
+            key: String::from(KEY_GRAVITY),
+            allowed_values: get_all_toggles().join(","),
+            default_value: get_default_toggle().to_string(),
+        },
+        SettingRule {
+            name: String::from("Boards"),
+            key: String::from(KEY_BOARDS),
+            allowed_values: get_all_board_counts().join(","),
+            default_value: get_default_board_count().get_value().to_string(),
+        },
+        SettingRule {
+            name: String::from("Players"),
+            key: String::from(KEY_PLAYERS),
+            allowed_values: get_all_player_counts().join(","),
+            default_value: get_default_player_count().get_value().to_string(),
+        },
+        SettingRule {
+            name: String::from("Turn Order"),
+            key: String::from(KEY_TURN_ORDER),
+            allowed_values: get_turn_order_allowed_values(),
+            default_value: String::from("First marks of X, O, Δ, □"),
+        },
+        SettingRule {
+            name: String::from("Win Rule"),
+            key: String::from(KEY_WIN_RULE),
+            allowed_values: get_all_win_rules().join(","),
+            default_value: get_default_win_rule().to_string(),
+        },
+        SettingRule {
+            name: String::from("Pieces"),
+            key: String::from(KEY_PIECES),
+            allowed_values: get_all_piece_counts().join(","),
+            default_value: get_default_piece_count(),
+        },
+        SettingRule {
+            name: String::from("Adjacent"),
+            key: String::from(KEY_ADJACENT),
+            allowed_values: get_all_toggles().join(","),
+            default_value: get_default_toggle().to_string(),
+        },
+        SettingRule {
+            name: String::from("Blocked Cells"),
+            key: String::from(KEY_BLOCKED),
+            allowed_values: get_all_blocked_cells().join(","),
+            default_value: get_default_blocked_cells(),
+        },
+        SettingRule {
+            name: String::from("Wrap"),
+            key: String::from(KEY_WRAP),
+            allowed_values: get_all_toggles().join(","),
+            default_value: get_default_toggle().to_string(),
+        },
+        SettingRule {
+            name: String::from("Swap"),
+            key: String::from(KEY_SWAP),
+            allowed_values: get_all_toggles().join(","),
+            default_value: get_default_toggle().to_string(),
+        },
+        SettingRule {
+            name: String::from("Win Length"),
+            key: String::from(KEY_WIN_LENGTH),
+            allowed_values: get_all_win_lengths().join(","),
+            default_value: get_default_win_length(),
         },
     ];
 
@@ -36,14 +175,118 @@ fn test_get_game_settings_rules() {
     let expected = serde_json::to_string(&vec![
         SettingRule {
             name: String::from("First Player"),
-            allowed_values: String::from("X,O"),
+            key: String::from("first_player"),
+            allowed_values: String::from("X,O,Δ,□"),
             default_value: String::from("X"),
         },
         SettingRule {
             name: String::from("Board Size"),
+            key: String::from("board_size"),
             allowed_values: String::from("3,4,5"),
             default_value: String::from("3"),
         },
+        SettingRule {
+            name: String::from("Width"),
+            key: String::from("width"),
+            allowed_values: String::from("Board Size,3,4,5,6,7,8"),
+            default_value: String::from("Board Size"),
+        },
+        SettingRule {
+            name: String::from("Height"),
+            key: String::from("height"),
+            allowed_values: String::from("Board Size,3,4,5,6,7,8"),
+            default_value: String::from("Board Size"),
+        },
+        SettingRule {
+            name: String::from("Early Draw"),
+            key: String::from("early_draw"),
+            allowed_values: String::from("Off,On"),
+            default_value: String::from("Off"),
+        },
+        SettingRule {
+            name: String::from("Time Control"),
+            key: String::from("time_control"),
+            allowed_values: String::from("Off,<seconds>,<seconds>+<increment>,<seconds>/move"),
+            default_value: String::from("Off"),
+        },
+        SettingRule {
+            name: String::from("Misere"),
+            key: String::from("misere"),
+            allowed_values: String::from("Off,On"),
+            default_value: String::from("Off"),
+        },
+        SettingRule {
+            name: String::from("Variant"),
+            key: String::from("variant"),
+            allowed_values: String::from("Classic,Ultimate,Qubic,Wild,Notakto,OrderChaos,Sliding"),
+            default_value: String::from("Classic"),
+        },
+        SettingRule {
+            name: String::from("Gravity"),
+            key: String::from("gravity"),
+            allowed_values: String::from("Off,On"),
+            default_value: String::from("Off"),
+        },
+        SettingRule {
+            name: String::from("Boards"),
+            key: String::from("boards"),
+            allowed_values: String::from("1,2,3,4,5"),
+            default_value: String::from("1"),
+        },
+        SettingRule {
+            name: String::from("Players"),
+            key: String::from("players"),
+            allowed_values: String::from("2,3,4"),
+            default_value: String::from("2"),
+        },
+        SettingRule {
+            name: String::from("Turn Order"),
+            key: String::from("turn_order"),
+            allowed_values: String::from("<marks in order, for example XOΔ or XOT>"),
+            default_value: String::from("First marks of X, O, Δ, □"),
+        },
+        SettingRule {
+            name: String::from("Win Rule"),
+            key: String::from("win_rule"),
+            allowed_values: String::from("FirstToLine,Elimination"),
+            default_value: String::from("FirstToLine"),
+        },
+        SettingRule {
+            name: String::from("Pieces"),
+            key: String::from("pieces"),
+            allowed_values: String::from("Line Length,3,4,5,6,7,8"),
+            default_value: String::from("Line Length"),
+        },
+        SettingRule {
+            name: String::from("Adjacent"),
+            key: String::from("adjacent"),
+            allowed_values: String::from("Off,On"),
+            default_value: String::from("Off"),
+        },
+        SettingRule {
+            name: String::from("Blocked Cells"),
+            key: String::from("blocked"),
+            allowed_values: String::from("Off,<cells separated by space>,random <count> <seed>"),
+            default_value: String::from("Off"),
+        },
+        SettingRule {
+            name: String::from("Wrap"),
+            key: String::from("wrap"),
+            allowed_values: String::from("Off,On"),
+            default_value: String::from("Off"),
+        },
+        SettingRule {
+            name: String::from("Swap"),
+            key: String::from("swap"),
+            allowed_values: String::from("Off,On"),
+            default_value: String::from("Off"),
+        },
+        SettingRule {
+            name: String::from("Win Length"),
+            key: String::from("win_length"),
+            allowed_values: String::from("Shorter Side,3,4,5,6,7,8"),
+            default_value: String::from("Shorter Side"),
+        },
     ])
     .unwrap();
 
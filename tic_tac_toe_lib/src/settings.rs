@@ -1,47 +1,207 @@
+use crate::types::blocked_cells::{
+    get_default as get_default_blocked_cells, parse as parse_blocked_cells, BlockedCells,
+};
+use crate::types::board_count::{
+    get_default as get_default_board_count, parse as parse_board_count, BoardCount,
+};
 use crate::types::board_dimension::{
-    get_default as get_default_board_dimension, parse as parse_board_dimension, BoardDimension,
+    get_default as get_default_board_dimension, parse as parse_board_dimension, parse_sides,
+    BoardDimension,
 };
 use crate::types::errors::TicTacToeError;
+use crate::types::piece_count::{
+    get_default as get_default_piece_count, parse as parse_piece_count, PieceCount,
+};
+use crate::types::player_count::parse as parse_player_count;
 use crate::types::player_mark::{
     get_default as get_default_player_mark, parse as parse_player_mark, PlayerMark,
 };
+use crate::types::time_control::{
+    get_default as get_default_time_control, parse as parse_time_control, TimeControl,
+};
+use crate::types::toggle::{get_default as get_default_toggle, parse as parse_toggle};
+use crate::types::turn_order::{
+    get_default as get_default_turn_order, parse as parse_turn_order, TurnOrder,
+};
+use crate::types::variant::{get_default as get_default_variant, parse as parse_variant, Variant};
+use crate::types::win_length::{
+    get_default as get_default_win_length, parse as parse_win_length, WinLength,
+};
+use crate::types::win_rule::{
+    get_default as get_default_win_rule, parse as parse_win_rule, WinRule,
+};
 use anyhow::bail;
 
-#[derive(Debug, PartialEq)]
+// Keys for settings given as 'key=value'. First Player and Board Size may also be
+// given by position, as the first two values.
+pub const KEY_FIRST_PLAYER: &str = "first_player";
+pub const KEY_BOARD_SIZE: &str = "board_size";
+pub const KEY_EARLY_DRAW: &str = "early_draw";
+pub const KEY_TIME_CONTROL: &str = "time_control";
+pub const KEY_MISERE: &str = "misere";
+pub const KEY_VARIANT: &str = "variant";
+pub const KEY_GRAVITY: &str = "gravity";
+pub const KEY_WIDTH: &str = "width";
+pub const KEY_HEIGHT: &str = "height";
+pub const KEY_BOARDS: &str = "boards";
+pub const KEY_PLAYERS: &str = "players";
+pub const KEY_TURN_ORDER: &str = "turn_order";
+pub const KEY_WIN_RULE: &str = "win_rule";
+pub const KEY_PIECES: &str = "pieces";
+pub const KEY_ADJACENT: &str = "adjacent";
+pub const KEY_BLOCKED: &str = "blocked";
+pub const KEY_WRAP: &str = "wrap";
+pub const KEY_SWAP: &str = "swap";
+pub const KEY_WIN_LENGTH: &str = "win_length";
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Settings {
     pub first_player: PlayerMark,
+    // Square of the board size unless width or height are given
     pub board_dimension: BoardDimension,
+    // Draw as soon as no line can be completed anymore
+    pub early_draw: bool,
+    // None plays without a clock
+    pub time_control: Option<TimeControl>,
+    // Completing a line loses instead of wins
+    pub misere: bool,
+    pub variant: Variant,
+    // Marks fall to the lowest empty cell of the column played
+    pub gravity: bool,
+    // Boards played at once, more than one only in Notakto
+    pub board_count: BoardCount,
+    // Players in the order they move, two unless the number of players is given
+    pub turn_order: TurnOrder,
+    // Only matters for more than two players
+    pub win_rule: WinRule,
+    // Pieces of every player in the Sliding variant, None for as many as the line length
+    pub piece_count: Option<PieceCount>,
+    // Pieces slide only to a cell next to theirs in the Sliding variant
+    pub adjacent: bool,
+    // Cells nobody can play, none by default
+    pub blocked_cells: BlockedCells,
+    // Lines continue across the edges of the board, like on a torus
+    pub wrap: bool,
+    // Second player may take over the first move instead of answering it
+    pub swap: bool,
+    // Marks in a row that win, None for as many as the shorter side
+    pub win_length: Option<WinLength>,
+}
+
+impl Settings {
+    // Length of the lines that decide the game on the board of these settings
+    pub fn get_line_length(&self) -> u8 {
+        match &self.win_length {
+            Some(win_length) => win_length.get_value(),
+            None => self.board_dimension.get_line_length(),
+        }
+    }
+}
+
+pub fn get_default() -> Settings {
+    Settings {
+        first_player: get_default_player_mark(),
+        board_dimension: get_default_board_dimension(),
+        early_draw: get_default_toggle().is_on(),
+        time_control: get_default_time_control(),
+        misere: get_default_toggle().is_on(),
+        variant: get_default_variant(),
+        gravity: get_default_toggle().is_on(),
+        board_count: get_default_board_count(),
+        turn_order: get_default_turn_order(),
+        win_rule: get_default_win_rule(),
+        piece_count: get_default_piece_count(),
+        adjacent: get_default_toggle().is_on(),
+        blocked_cells: get_default_blocked_cells(),
+        wrap: get_default_toggle().is_on(),
+        swap: get_default_toggle().is_on(),
+        win_length: get_default_win_length(),
+    }
 }
 
 pub fn parse(s: &str) -> Result<Settings, TicTacToeError> {
     let str = s.trim();
 
+    let mut settings = get_default();
+    #[allow(clippy::len_zero)]
     if str.len() == 0 {
-        return Ok(Settings {
-            first_player: get_default_player_mark(),
-            board_dimension: get_default_board_dimension(),
-        });
+        return Ok(settings);
     }
 
-    let parts: Vec<&str> = str.split(",").collect();
-    match parts.len() {
+    let parts: Vec<&str> = str.split(',').collect();
+    let (named, positional): (Vec<&str>, Vec<&str>) =
+        parts.into_iter().partition(|part| part.contains('='));
+
+    match positional.len() {
+        0 => {}
         1 => {
-            let fp = parse_player_mark(parts[0])?;
-            return Ok(Settings {
-                first_player: fp,
-                board_dimension: get_default_board_dimension(),
-            });
+            settings.first_player = parse_player_mark(positional[0])?;
         }
         2 => {
-            let fp = parse_player_mark(parts[0])?;
-            let bd = parse_board_dimension(parts[1])?;
-            return Ok(Settings {
-                first_player: fp,
-                board_dimension: bd,
-            });
+            settings.first_player = parse_player_mark(positional[0])?;
+            settings.board_dimension = parse_board_dimension(positional[1])?;
         }
         _ => bail!("More than 2 settings provided: '{}'", s),
     }
+
+    // width and height change the sides of the board size, wherever it is given
+    let mut width: Option<&str> = None;
+    let mut height: Option<&str> = None;
+    // the turn order must name as many players as given
+    let mut players: Option<&str> = None;
+    let mut turn_order: Option<&str> = None;
+    // blocked cells and the win length are checked against the final board
+    let mut blocked: Option<&str> = None;
+    let mut win_length: Option<&str> = None;
+
+    for part in named {
+        let (key, value) = part.split_once('=').unwrap();
+        match key.trim().to_lowercase().as_str() {
+            KEY_FIRST_PLAYER => settings.first_player = parse_player_mark(value)?,
+            KEY_BOARD_SIZE => settings.board_dimension = parse_board_dimension(value)?,
+            KEY_EARLY_DRAW => settings.early_draw = parse_toggle(value)?.is_on(),
+            KEY_TIME_CONTROL => settings.time_control = parse_time_control(value)?,
+            KEY_MISERE => settings.misere = parse_toggle(value)?.is_on(),
+            KEY_VARIANT => settings.variant = parse_variant(value)?,
+            KEY_GRAVITY => settings.gravity = parse_toggle(value)?.is_on(),
+            KEY_WIDTH => width = Some(value),
+            KEY_HEIGHT => height = Some(value),
+            KEY_BOARDS => settings.board_count = parse_board_count(value)?,
+            KEY_PLAYERS => players = Some(value),
+            KEY_TURN_ORDER => turn_order = Some(value),
+            KEY_WIN_RULE => settings.win_rule = parse_win_rule(value)?,
+            KEY_PIECES => settings.piece_count = parse_piece_count(value)?,
+            KEY_ADJACENT => settings.adjacent = parse_toggle(value)?.is_on(),
+            KEY_BLOCKED => blocked = Some(value),
+            KEY_WRAP => settings.wrap = parse_toggle(value)?.is_on(),
+            KEY_SWAP => settings.swap = parse_toggle(value)?.is_on(),
+            KEY_WIN_LENGTH => win_length = Some(value),
+            _ => bail!("Setting '{}' is not recognized", key.trim()),
+        }
+    }
+    settings.board_dimension = parse_sides(&settings.board_dimension, width, height)?;
+    settings.blocked_cells = parse_blocked_cells(&settings.board_dimension, blocked.unwrap_or(""))?;
+    settings.win_length = parse_win_length(&settings.board_dimension, win_length.unwrap_or(""))?;
+    let player_count = players.map(parse_player_count).transpose()?;
+    settings.turn_order = parse_turn_order(turn_order.unwrap_or(""), player_count.as_ref())?;
+    if !settings
+        .turn_order
+        .get_players()
+        .contains(&settings.first_player)
+    {
+        bail!(
+            "First Player {} does not play in a game of {} players",
+            settings.first_player,
+            settings.turn_order.get_players().len()
+        );
+    }
+
+    Ok(settings)
+}
+
+// Front End picks the game type by the variant
+pub fn get_variant(settings_str: &str) -> Result<String, TicTacToeError> {
+    Ok(parse(settings_str)?.variant.to_string())
 }
 
 #[test]
@@ -49,6 +209,20 @@ fn test_parse_empty_return_default() {
     let default_settings = Settings {
         first_player: get_default_player_mark(),
         board_dimension: get_default_board_dimension(),
+        early_draw: false,
+        time_control: None,
+        misere: false,
+        variant: Variant::Classic,
+        gravity: false,
+        board_count: get_default_board_count(),
+        turn_order: get_default_turn_order(),
+        win_rule: WinRule::FirstToLine,
+        piece_count: None,
+        adjacent: false,
+        blocked_cells: get_default_blocked_cells(),
+        wrap: false,
+        swap: false,
+        win_length: None,
     };
     assert_eq!(parse("").unwrap(), default_settings);
     assert_eq!(parse(" ").unwrap(), default_settings);
@@ -61,14 +235,14 @@ fn test_parse_one_argument_success() {
         parse("x").unwrap(),
         Settings {
             first_player: PlayerMark::X,
-            board_dimension: get_default_board_dimension(),
+            ..get_default()
         }
     );
     assert_eq!(
         parse(" O ").unwrap(),
         Settings {
             first_player: PlayerMark::O,
-            board_dimension: get_default_board_dimension(),
+            ..get_default()
         }
     );
 }
@@ -86,6 +260,7 @@ fn test_parse_two_arguments_success() {
         Settings {
             first_player: PlayerMark::X,
             board_dimension: parse_board_dimension("4").unwrap(),
+            ..get_default()
         }
     );
 }
@@ -113,3 +288,150 @@ fn test_parse_two_arguments_board_dimension_greater_than_max() {
 fn test_parse_three_arguments() {
     let _s = parse("X,4,O").unwrap();
 }
+
+#[test]
+fn test_parse_named_settings() {
+    assert_eq!(
+        parse("O, 4, early_draw=On").unwrap(),
+        Settings {
+            first_player: PlayerMark::O,
+            board_dimension: parse_board_dimension("4").unwrap(),
+            early_draw: true,
+            time_control: None,
+            misere: false,
+            variant: Variant::Classic,
+            gravity: false,
+            board_count: get_default_board_count(),
+            turn_order: get_default_turn_order(),
+            win_rule: WinRule::FirstToLine,
+            piece_count: None,
+            adjacent: false,
+            blocked_cells: get_default_blocked_cells(),
+            wrap: false,
+            swap: false,
+            win_length: None,
+        }
+    );
+    assert_eq!(
+        parse(" Early_Draw = on , board_size=5").unwrap(),
+        Settings {
+            board_dimension: parse_board_dimension("5").unwrap(),
+            early_draw: true,
+            ..get_default()
+        }
+    );
+}
+
+#[test]
+fn test_parse_time_control() {
+    assert_eq!(
+        parse("O,3,time_control=60+1").unwrap(),
+        Settings {
+            first_player: PlayerMark::O,
+            time_control: parse_time_control("60+1").unwrap(),
+            ..get_default()
+        }
+    );
+}
+
+#[test]
+fn test_parse_misere() {
+    assert_eq!(
+        parse("X,3,misere=On").unwrap(),
+        Settings {
+            misere: true,
+            ..get_default()
+        }
+    );
+}
+
+#[test]
+fn test_parse_width_and_height() {
+    let settings = parse("X,width=7,height=6").unwrap();
+    assert_eq!(settings.board_dimension.get_width(), 7);
+    assert_eq!(settings.board_dimension.get_height(), 6);
+
+    // a missing side keeps the board size, also when the size is given later
+    let settings = parse("height=3, board_size=4").unwrap();
+    assert_eq!(settings.board_dimension.get_width(), 4);
+    assert_eq!(settings.board_dimension.get_height(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Board Width 'wide' is not a number")]
+fn test_parse_width_not_a_number() {
+    let _s = parse("width=wide").unwrap();
+}
+
+#[test]
+fn test_parse_boards() {
+    assert_eq!(
+        parse("X,3,variant=Notakto,boards=3").unwrap(),
+        Settings {
+            variant: Variant::Notakto,
+            board_count: parse_board_count("3").unwrap(),
+            ..get_default()
+        }
+    );
+}
+
+#[test]
+fn test_parse_players() {
+    let settings = parse("Δ,4,players=3,win_rule=Elimination").unwrap();
+    assert_eq!(settings.first_player, PlayerMark::Triangle);
+    assert_eq!(settings.turn_order.get_players().len(), 3);
+    assert_eq!(settings.win_rule, WinRule::Elimination);
+
+    let settings = parse("X,4,turn_order=XΔO").unwrap();
+    assert_eq!(
+        settings.turn_order.get_players(),
+        [PlayerMark::X, PlayerMark::Triangle, PlayerMark::O]
+    );
+}
+
+#[test]
+#[should_panic(expected = "First Player □ does not play in a game of 3 players")]
+fn test_parse_first_player_not_playing() {
+    let _s = parse("S,4,players=3").unwrap();
+}
+
+#[test]
+fn test_parse_sliding() {
+    let settings = parse("X,4,variant=Sliding,pieces=5,adjacent=On").unwrap();
+    assert_eq!(settings.variant, Variant::Sliding);
+    assert_eq!(settings.piece_count, parse_piece_count("5").unwrap());
+    assert!(settings.adjacent);
+}
+
+#[test]
+fn test_parse_blocked_cells() {
+    // the cells are checked against the board size given after them
+    let settings = parse("blocked=1 16,board_size=4").unwrap();
+    assert_eq!(settings.blocked_cells.get_cell_numbers(), [1, 16]);
+}
+
+#[test]
+fn test_parse_win_length() {
+    // the length is checked against the width and height given after it
+    let settings = parse("win_length=4,width=7,height=6").unwrap();
+    assert_eq!(settings.get_line_length(), 4);
+    assert_eq!(parse("X,width=7,height=6").unwrap().get_line_length(), 6);
+}
+
+#[test]
+fn test_get_variant() {
+    assert_eq!(get_variant("X,3").unwrap(), "Classic");
+    assert_eq!(get_variant("X,3,variant=ultimate").unwrap(), "Ultimate");
+}
+
+#[test]
+#[should_panic(expected = "Setting 'colour' is not recognized")]
+fn test_parse_unknown_named_setting() {
+    let _s = parse("X,3,colour=red").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Value 'maybe' is not recognized, use On or Off")]
+fn test_parse_named_setting_wrong_value() {
+    let _s = parse("early_draw=maybe").unwrap();
+}
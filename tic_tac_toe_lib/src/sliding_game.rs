@@ -0,0 +1,354 @@
+// NOTE 22: Public type for the Sliding variant, like three men's morris. Every player has
+//          a limited number of pieces. Once all are placed a move slides one of the own
+//          pieces to an empty cell, with the adjacent rule only to a cell next to it.
+//          The third time the same position comes up the game is a draw.
+
+#[cfg(test)]
+use crate::game_state::deserialize_sliding_game_state;
+use crate::game_state::{OutcomeState, PiecesToPlace, SlidingGameState};
+use crate::settings::{parse, Settings};
+use crate::state_calculation::calculate_result_after_move;
+use crate::types::board::{Board, CellGrid};
+use crate::types::board_move::parse as parse_board_move;
+use crate::types::errors::TicTacToeError;
+use crate::types::outcome::{DrawReason, Outcome, WinReason};
+use crate::types::player_mark::{get_two_player_marks, PlayerMark};
+use crate::types::slide_move::parse as parse_slide_move;
+use crate::types::variant::Variant;
+use anyhow::bail;
+
+// A position repeated this many times is a draw
+const REPETITIONS_FOR_DRAW: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct SlidingGame {
+    settings: Settings,
+    board: Board,
+    piece_count: u8,
+    next_on_move: PlayerMark,
+    outcome: Outcome,
+    moves: Vec<String>,
+    // Every position of the sliding phase with the player on move, in the order they came up
+    positions: Vec<(Board, PlayerMark)>,
+}
+
+impl SlidingGame {
+    pub fn new(settings_str: &str) -> Result<SlidingGame, TicTacToeError> {
+        let settings = parse(settings_str)?;
+        if settings.variant != Variant::Sliding {
+            bail!("Variant '{}' is not Sliding", settings.variant);
+        }
+        if settings.early_draw
+            || settings.misere
+            || settings.gravity
+            || settings.time_control.is_some()
+            || settings.board_count.get_value() > 1
+            || settings.turn_order.get_players().len() > 2
+            || !settings.blocked_cells.is_empty()
+            || settings.wrap
+            || settings.swap
+            || settings.win_length.is_some()
+        {
+            bail!("Sliding supports only First Player, Board Size, Pieces and Adjacent settings");
+        }
+
+        let board = Board::new(settings.board_dimension.clone());
+        let line_length = board.dimension.get_line_length();
+        let piece_count = settings
+            .piece_count
+            .as_ref()
+            .map_or(line_length, |pc| pc.get_value());
+        if piece_count < line_length {
+            bail!(
+                "Every player needs at least {} pieces to complete a line",
+                line_length
+            );
+        }
+        if 2 * piece_count >= board.dimension.get_cell_count() {
+            bail!(
+                "{} pieces for every player leave no empty cell to slide to",
+                piece_count
+            );
+        }
+
+        Ok(SlidingGame {
+            next_on_move: settings.first_player.clone(),
+            settings,
+            board,
+            piece_count,
+            outcome: Outcome::InProgress,
+            moves: vec![],
+            positions: vec![],
+        })
+    }
+
+    // A cell while placing, for example 5, then from-to, for example 1-5
+    pub fn play_move(&mut self, move_str: &str) -> Result<(), TicTacToeError> {
+        if self.outcome.is_finished() {
+            bail!("Game is finished, you can not play a move");
+        }
+
+        let mover = self.next_on_move.clone();
+        let (row_idx, col_idx) = if self.get_pieces_to_place(&mover) > 0 {
+            let board_move = parse_board_move(&self.board.dimension, move_str)?;
+            let (row_idx, col_idx) = board_move.get_indices();
+            let (row_idx, col_idx) = (row_idx as usize, col_idx as usize);
+
+            if let Some(pm) = self.board.get_cell(row_idx, col_idx) {
+                bail!("Cell {} is already filled with '{}'", move_str.trim(), pm);
+            }
+
+            self.board.set_cell(row_idx, col_idx, Some(mover.clone()));
+            self.moves.push(board_move.get_value().to_string());
+            (row_idx, col_idx)
+        } else {
+            let slide_move = parse_slide_move(&self.board.dimension, move_str)?;
+            let (from_row_idx, from_col_idx) = slide_move.from.get_indices();
+            let (from_row_idx, from_col_idx) = (from_row_idx as usize, from_col_idx as usize);
+            let (row_idx, col_idx) = slide_move.to.get_indices();
+            let (row_idx, col_idx) = (row_idx as usize, col_idx as usize);
+
+            if self.board.get_cell(from_row_idx, from_col_idx) != Some(mover.clone()) {
+                bail!(
+                    "Cell {} does not hold a piece of player {}",
+                    slide_move.from.get_value(),
+                    mover
+                );
+            }
+            if let Some(pm) = self.board.get_cell(row_idx, col_idx) {
+                bail!(
+                    "Cell {} is already filled with '{}'",
+                    slide_move.to.get_value(),
+                    pm
+                );
+            }
+            if self.settings.adjacent && !slide_move.is_adjacent() {
+                bail!(
+                    "Piece on cell {} can slide only to a cell next to it",
+                    slide_move.from.get_value()
+                );
+            }
+
+            self.board.set_cell(from_row_idx, from_col_idx, None);
+            self.board.set_cell(row_idx, col_idx, Some(mover.clone()));
+            self.moves.push(slide_move.to_string());
+            (row_idx, col_idx)
+        };
+        self.next_on_move = mover.get_opponent();
+
+        // the board never fills, there is always a cell to slide to
+        self.outcome = calculate_result_after_move(&self.board, row_idx, col_idx, false);
+        if self.outcome.is_finished() {
+            return Ok(());
+        }
+
+        // positions can only repeat once every piece is placed
+        if self.is_sliding_phase() {
+            let position = (self.board.clone(), self.next_on_move.clone());
+            self.positions.push(position.clone());
+            if self.positions.iter().filter(|p| **p == position).count() >= REPETITIONS_FOR_DRAW {
+                self.outcome = Outcome::Draw {
+                    reason: DrawReason::Repetition,
+                };
+                return Ok(());
+            }
+        }
+
+        // with the adjacent rule all pieces of the opponent can be walled in
+        if self.legal_moves().is_empty() {
+            self.outcome = Outcome::Win {
+                winner: mover,
+                reason: WinReason::Blocked,
+                line: vec![],
+            };
+        }
+
+        Ok(())
+    }
+
+    pub fn legal_moves(&self) -> Vec<String> {
+        if self.outcome.is_finished() {
+            return vec![];
+        }
+
+        let board_width = self.board.dimension.get_width() as usize;
+        let cells: Vec<(usize, usize)> = (0..self.board.dimension.get_cell_count() as usize)
+            .map(|cell_idx| (cell_idx / board_width, cell_idx % board_width))
+            .collect();
+        let empty_cells: Vec<&(usize, usize)> = cells
+            .iter()
+            .filter(|(row_idx, col_idx)| self.board.get_cell(*row_idx, *col_idx).is_none())
+            .collect();
+        let cell_number = |(row_idx, col_idx): &(usize, usize)| row_idx * board_width + col_idx + 1;
+
+        if !self.is_sliding_phase() {
+            return empty_cells
+                .into_iter()
+                .map(|cell| cell_number(cell).to_string())
+                .collect();
+        }
+
+        let mut moves: Vec<String> = vec![];
+        for from in cells.iter().filter(|(row_idx, col_idx)| {
+            self.board.get_cell(*row_idx, *col_idx) == Some(self.next_on_move.clone())
+        }) {
+            for to in &empty_cells {
+                if !self.settings.adjacent
+                    || (from.0.abs_diff(to.0) <= 1 && from.1.abs_diff(to.1) <= 1)
+                {
+                    moves.push(format!("{}-{}", cell_number(from), cell_number(to)));
+                }
+            }
+        }
+        moves
+    }
+
+    pub fn get_outcome(&self) -> &Outcome {
+        &self.outcome
+    }
+
+    fn get_pieces_to_place(&self, player_mark: &PlayerMark) -> u8 {
+        self.piece_count - self.board.get_marks(player_mark).count_ones() as u8
+    }
+
+    // The player on move moves last while placing, so both players placed all pieces
+    fn is_sliding_phase(&self) -> bool {
+        self.get_pieces_to_place(&self.next_on_move) == 0
+    }
+
+    pub fn get_state(&self) -> String {
+        let board_width = self.board.dimension.get_width() as usize;
+        let board_height = self.board.dimension.get_height() as usize;
+
+        let board = (0..board_height)
+            .map(|row_idx| {
+                (0..board_width)
+                    .map(|col_idx| match self.board.get_cell(row_idx, col_idx) {
+                        None => (row_idx * board_width + col_idx + 1).to_string(),
+                        Some(pm) => pm.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let sgs = SlidingGameState {
+            board,
+            next_on_move: self.next_on_move.to_string(),
+            phase: String::from(match self.is_sliding_phase() {
+                true => "Sliding",
+                false => "Placing",
+            }),
+            pieces_to_place: get_two_player_marks()
+                .iter()
+                .map(|pm| PiecesToPlace {
+                    mark: pm.to_string(),
+                    count: self.get_pieces_to_place(pm),
+                })
+                .collect(),
+            finished: self.outcome.is_finished(),
+            outcome: OutcomeState::from(&self.outcome),
+            moves: self.moves.clone(),
+        };
+
+        serde_json::to_string(&sgs).unwrap()
+    }
+}
+
+#[cfg(test)]
+fn play_placing_phase() -> SlidingGame {
+    // X: 1 2 9, O: 4 5 7, X can complete the first row by sliding 9 to 3
+    let mut g = SlidingGame::new("X,3,variant=Sliding").unwrap();
+    for board_move in ["1", "4", "2", "5", "9", "7"] {
+        g.play_move(board_move).unwrap();
+    }
+    g
+}
+
+#[test]
+fn test_new_state() {
+    let g = SlidingGame::new("O,3,variant=Sliding").unwrap();
+    let state = deserialize_sliding_game_state(&g.get_state());
+
+    assert_eq!(state.next_on_move, "O");
+    assert_eq!(state.phase, "Placing");
+    assert_eq!(
+        state.pieces_to_place,
+        vec![
+            PiecesToPlace {
+                mark: String::from("X"),
+                count: 3,
+            },
+            PiecesToPlace {
+                mark: String::from("O"),
+                count: 3,
+            },
+        ]
+    );
+    assert_eq!(g.legal_moves().len(), 9);
+}
+
+#[test]
+fn test_slide_completes_line() {
+    let mut g = play_placing_phase();
+    let state = deserialize_sliding_game_state(&g.get_state());
+    assert_eq!(state.phase, "Sliding");
+    // three pieces to three empty cells
+    assert_eq!(g.legal_moves().len(), 9);
+    assert_eq!(g.legal_moves()[0], "1-3");
+
+    g.play_move("9-3").unwrap();
+    let state = deserialize_sliding_game_state(&g.get_state());
+    assert_eq!(state.board[2], vec!["O", "8", "9"]);
+    assert_eq!(state.outcome.winner, Some(String::from("X")));
+    assert_eq!(state.outcome.line, vec![1, 2, 3]);
+    assert_eq!(state.moves.last().unwrap(), "9-3");
+}
+
+#[test]
+#[should_panic(expected = "Cell 4 does not hold a piece of player X")]
+fn test_slide_opponent_piece() {
+    let mut g = play_placing_phase();
+    g.play_move("4-3").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Piece on cell 9 can slide only to a cell next to it")]
+fn test_slide_not_adjacent() {
+    let mut g = SlidingGame::new("X,3,variant=Sliding,adjacent=On").unwrap();
+    for board_move in ["1", "4", "2", "5", "9", "7"] {
+        g.play_move(board_move).unwrap();
+    }
+    assert_eq!(g.legal_moves(), vec!["2-3", "2-6", "9-6", "9-8"]);
+    g.play_move("9-3").unwrap();
+}
+
+#[test]
+fn test_repetition_is_draw() {
+    let mut g = play_placing_phase();
+    // both players slide a piece away and back, twice
+    for _ in 0..2 {
+        assert!(!g.get_outcome().is_finished());
+        for board_move in ["9-8", "7-3", "8-9", "3-7"] {
+            g.play_move(board_move).unwrap();
+        }
+    }
+
+    assert_eq!(
+        g.get_outcome(),
+        &Outcome::Draw {
+            reason: DrawReason::Repetition,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Every player needs at least 4 pieces to complete a line")]
+fn test_too_few_pieces() {
+    let _g = SlidingGame::new("X,4,variant=Sliding,pieces=3").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Sliding supports only First Player, Board Size, Pieces and Adjacent")]
+fn test_unsupported_settings() {
+    let _g = SlidingGame::new("X,3,variant=Sliding,misere=On").unwrap();
+}
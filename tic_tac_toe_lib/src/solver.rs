@@ -0,0 +1,461 @@
+// NOTE 12: Exhaustive solver. Every reachable position is stored with its game-theoretic
+//          value and distance to the end, so perfect answers need no search at all.
+
+use crate::game::Game;
+use crate::search::{get_terminal_score, WIN_SCORE};
+use crate::settings::{parse, Settings};
+use crate::types::board::CanonicalKey;
+use crate::types::errors::TicTacToeError;
+use crate::types::variant::Variant;
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const FILE_MAGIC: &[u8; 4] = b"TTTB";
+const FILE_VERSION: u8 = 2;
+const ENTRY_SIZE: usize = CanonicalKey::BYTE_COUNT + 3;
+const NO_CELL: u8 = u8::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TablebaseEntry {
+    // 1 win, 0 draw, -1 loss for the player on move
+    value: i8,
+    moves_to_end: u8,
+    // Index of the best cell in the canonical frame, NO_CELL for finished games
+    best_cell: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct TablebaseProbe {
+    pub verdict: String,
+    pub moves_to_end: u8,
+}
+
+#[derive(Debug)]
+pub struct Tablebase {
+    settings_str: String,
+    settings: Settings,
+    entries: HashMap<CanonicalKey, TablebaseEntry>,
+}
+
+impl Tablebase {
+    // Enumerates all positions reachable from the start with the given settings
+    pub fn solve(settings_str: &str) -> Result<Tablebase, TicTacToeError> {
+        let game = Game::new(settings_str)?.without_timer();
+        // positions are stored under their symmetric form, marks falling down break symmetry
+        if game.get_settings().gravity {
+            bail!("Tablebase can not be solved with Gravity");
+        }
+        // best moves are stored as cells, without the mark a wild move needs
+        if game.get_settings().variant == Variant::Wild {
+            bail!("Tablebase can not be solved for Wild");
+        }
+        if game.get_settings().turn_order.get_players().len() > 2 {
+            bail!("Tablebase can be solved only for two players");
+        }
+
+        let mut entries: HashMap<CanonicalKey, TablebaseEntry> = HashMap::new();
+        solve_position(&game, &mut entries);
+
+        Ok(Tablebase {
+            settings_str: String::from(settings_str.trim()),
+            settings: parse(settings_str)?,
+            entries,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // JSON with the verdict for the player on move, None for positions of other settings
+    pub fn probe(&self, game: &Game) -> Option<String> {
+        let entry = self.get_entry(game)?;
+        let probe = TablebaseProbe {
+            verdict: String::from(match entry.value {
+                1 => "Win",
+                0 => "Draw",
+                _ => "Loss",
+            }),
+            moves_to_end: entry.moves_to_end,
+        };
+        Some(serde_json::to_string(&probe).unwrap())
+    }
+
+    // Single best move from the stored entry, without looking at the children
+    pub fn get_best_move(&self, game: &Game) -> Option<String> {
+        let entry = self.get_entry(game)?;
+        if entry.best_cell == NO_CELL {
+            return None;
+        }
+
+        let board = game.get_board();
+        let board_width = board.dimension.get_width() as usize;
+        let (_, symmetry) = board.canonical_key();
+        let canonical_cell = (
+            entry.best_cell as usize / board_width,
+            entry.best_cell as usize % board_width,
+        );
+        let (row_idx, col_idx) = board.get_real_cell(symmetry, canonical_cell);
+
+        Some((row_idx * board_width + col_idx + 1).to_string())
+    }
+
+    // All moves that keep the best value, fastest win or slowest loss first
+    pub fn get_best_moves(&self, game: &Game) -> Vec<String> {
+        let mut best: Option<TablebaseEntry> = None;
+        let mut best_moves: Vec<String> = vec![];
+
+        for board_move in game.legal_moves() {
+            let mut child = game.without_timer();
+            child.play_move(&board_move).unwrap();
+            let candidate = match self.get_entry(&child) {
+                Some(entry) => get_parent_entry(entry),
+                None => return vec![],
+            };
+
+            if best.is_none_or(|b| is_better(candidate, b)) {
+                best = Some(candidate);
+                best_moves = vec![board_move];
+            } else if best.is_some_and(|b| !is_better(b, candidate)) {
+                best_moves.push(board_move);
+            }
+        }
+        best_moves
+    }
+
+    // Score of every legal move like the search gives it, None if a position is missing
+    pub fn score_moves(&self, game: &Game) -> Option<Vec<(String, i32)>> {
+        let mut scores: Vec<(String, i32)> = vec![];
+        for board_move in game.legal_moves() {
+            let mut child = game.without_timer();
+            child.play_move(&board_move).unwrap();
+            let entry = get_parent_entry(self.get_entry(&child)?);
+
+            let score = entry.value as i32 * (WIN_SCORE - entry.moves_to_end as i32);
+            scores.push((board_move, score));
+        }
+        Some(scores)
+    }
+
+    pub(crate) fn is_solved_for(&self, settings: &Settings) -> bool {
+        *settings == self.settings
+    }
+
+    // Header with settings, followed by fixed size (key, value, moves to end) entries
+    pub fn save(&self, path: &Path) -> Result<(), TicTacToeError> {
+        let settings_bytes = self.settings_str.as_bytes();
+
+        let mut bytes: Vec<u8> = vec![];
+        bytes.extend_from_slice(FILE_MAGIC);
+        bytes.push(FILE_VERSION);
+        bytes.extend_from_slice(&(settings_bytes.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(settings_bytes);
+        bytes.extend_from_slice(&(self.entries.len() as u64).to_le_bytes());
+
+        let mut keys: Vec<&CanonicalKey> = self.entries.keys().collect();
+        keys.sort();
+        for key in keys {
+            let entry = self.entries[key];
+            bytes.extend_from_slice(&key.to_bytes());
+            bytes.push(entry.value as u8);
+            bytes.push(entry.moves_to_end);
+            bytes.push(entry.best_cell);
+        }
+
+        fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Tablebase, TicTacToeError> {
+        let bytes = fs::read(path)?;
+
+        if bytes.len() < 7 || &bytes[0..4] != FILE_MAGIC {
+            bail!("File '{}' is not a tablebase", path.display());
+        }
+        if bytes[4] != FILE_VERSION {
+            bail!("Tablebase version {} is not supported", bytes[4]);
+        }
+
+        let settings_len = u16::from_le_bytes([bytes[5], bytes[6]]) as usize;
+        let entries_start = 7 + settings_len + 8;
+        if bytes.len() < entries_start {
+            bail!("Tablebase file '{}' is truncated", path.display());
+        }
+        let settings_str = String::from_utf8(bytes[7..7 + settings_len].to_vec())?;
+        let entry_count =
+            u64::from_le_bytes(bytes[7 + settings_len..entries_start].try_into().unwrap()) as usize;
+        let file_len = entry_count
+            .checked_mul(ENTRY_SIZE)
+            .and_then(|entries_len| entries_len.checked_add(entries_start));
+        if file_len != Some(bytes.len()) {
+            bail!("Tablebase file '{}' is truncated", path.display());
+        }
+
+        let mut entries: HashMap<CanonicalKey, TablebaseEntry> =
+            HashMap::with_capacity(entry_count);
+        for chunk in bytes[entries_start..].chunks(ENTRY_SIZE) {
+            let (key_bytes, entry_bytes) = chunk.split_at(CanonicalKey::BYTE_COUNT);
+            entries.insert(
+                CanonicalKey::from_bytes(key_bytes),
+                TablebaseEntry {
+                    value: entry_bytes[0] as i8,
+                    moves_to_end: entry_bytes[1],
+                    best_cell: entry_bytes[2],
+                },
+            );
+        }
+
+        Ok(Tablebase {
+            settings: parse(&settings_str)?,
+            settings_str,
+            entries,
+        })
+    }
+
+    fn get_entry(&self, game: &Game) -> Option<TablebaseEntry> {
+        if !self.is_solved_for(game.get_settings()) {
+            return None;
+        }
+
+        self.entries.get(&get_position_key(game)).copied()
+    }
+}
+
+fn solve_position(
+    game: &Game,
+    entries: &mut HashMap<CanonicalKey, TablebaseEntry>,
+) -> TablebaseEntry {
+    let key = get_position_key(game);
+    if let Some(entry) = entries.get(&key) {
+        return *entry;
+    }
+
+    let entry = if game.is_finished() {
+        TablebaseEntry {
+            value: get_terminal_score(game, 0).signum() as i8,
+            moves_to_end: 0,
+            best_cell: NO_CELL,
+        }
+    } else {
+        let board = game.get_board();
+        let board_width = board.dimension.get_width() as usize;
+        let (_, symmetry) = board.canonical_key();
+
+        let mut best: Option<TablebaseEntry> = None;
+        for board_move in game.legal_moves() {
+            let mut child = game.clone();
+            child.play_move(&board_move).unwrap();
+
+            let mut candidate = get_parent_entry(solve_position(&child, entries));
+            if best.is_none_or(|b| is_better(candidate, b)) {
+                let move_idx = board_move.parse::<usize>().unwrap() - 1;
+                let (row_idx, col_idx) = symmetry.apply(
+                    &board.dimension,
+                    (move_idx / board_width, move_idx % board_width),
+                );
+                candidate.best_cell = (row_idx * board_width + col_idx) as u8;
+                best = Some(candidate);
+            }
+        }
+        best.unwrap()
+    };
+
+    entries.insert(key, entry);
+    entry
+}
+
+// Entry of a child position, seen from the player who moved into it
+fn get_parent_entry(child: TablebaseEntry) -> TablebaseEntry {
+    TablebaseEntry {
+        value: -child.value,
+        moves_to_end: child.moves_to_end + 1,
+        best_cell: NO_CELL,
+    }
+}
+
+// Higher value first, then win fast, lose slow and draw fast
+fn is_better(a: TablebaseEntry, b: TablebaseEntry) -> bool {
+    if a.value != b.value {
+        return a.value > b.value;
+    }
+    if a.value < 0 {
+        a.moves_to_end > b.moves_to_end
+    } else {
+        a.moves_to_end < b.moves_to_end
+    }
+}
+
+// Symmetric positions share one entry. With fixed settings the player on move
+// follows from the number of marks, so the board alone identifies the position.
+fn get_position_key(game: &Game) -> CanonicalKey {
+    let (key, _) = game.get_board().canonical_key();
+    key
+}
+
+#[test]
+fn test_solve_3x3() {
+    let tablebase = Tablebase::solve("X,3").unwrap();
+
+    // All reachable 3x3 positions up to symmetry, counting finished ones
+    assert_eq!(tablebase.len(), 765);
+
+    let g = Game::new("X,3").unwrap();
+    let probe: TablebaseProbe = serde_json::from_str(&tablebase.probe(&g).unwrap()).unwrap();
+    assert_eq!(probe.verdict, "Draw");
+    assert_eq!(probe.moves_to_end, 9);
+}
+
+#[test]
+fn test_probe_forced_win() {
+    let tablebase = Tablebase::solve("X,3").unwrap();
+
+    let mut g = Game::new("X,3").unwrap();
+    g.play_move("1").unwrap();
+    g.play_move("2").unwrap();
+    g.play_move("5").unwrap();
+    g.play_move("3").unwrap();
+
+    let probe: TablebaseProbe = serde_json::from_str(&tablebase.probe(&g).unwrap()).unwrap();
+    assert_eq!(probe.verdict, "Win");
+    assert_eq!(probe.moves_to_end, 1);
+    assert_eq!(tablebase.get_best_moves(&g), vec!["9"]);
+    assert_eq!(tablebase.get_best_move(&g), Some(String::from("9")));
+}
+
+#[test]
+fn test_get_best_move_is_among_best_moves() {
+    let tablebase = Tablebase::solve("X,3").unwrap();
+
+    // Positions reached through different symmetries
+    for moves in [
+        vec!["1", "2"],
+        vec!["3", "6"],
+        vec!["9", "8"],
+        vec!["7", "4"],
+    ] {
+        let mut g = Game::new("X,3").unwrap();
+        for board_move in moves {
+            g.play_move(board_move).unwrap();
+        }
+
+        let best_move = tablebase.get_best_move(&g).unwrap();
+        assert!(tablebase.get_best_moves(&g).contains(&best_move));
+    }
+}
+
+#[test]
+fn test_probe_other_settings() {
+    let tablebase = Tablebase::solve("X,3").unwrap();
+    let g = Game::new("X,4").unwrap();
+    assert_eq!(tablebase.probe(&g), None);
+
+    let g = Game::new("O,3").unwrap();
+    assert_eq!(tablebase.probe(&g), None);
+    assert!(tablebase.get_best_moves(&g).is_empty());
+}
+
+#[test]
+fn test_save_load() {
+    let tablebase = Tablebase::solve("X,3").unwrap();
+    let path = std::env::temp_dir().join("tic_tac_toe_test_save_load.tttb");
+
+    tablebase.save(&path).unwrap();
+    let loaded = Tablebase::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.settings, tablebase.settings);
+    assert_eq!(loaded.entries, tablebase.entries);
+}
+
+#[test]
+#[should_panic(expected = "is not a tablebase")]
+fn test_load_wrong_file() {
+    let path = std::env::temp_dir().join("tic_tac_toe_test_load_wrong_file.tttb");
+    fs::write(&path, b"not a tablebase").unwrap();
+
+    let result = Tablebase::load(&path);
+    fs::remove_file(&path).unwrap();
+    result.unwrap();
+}
+
+#[test]
+#[should_panic(expected = "is truncated")]
+fn test_load_entry_count_overflow() {
+    let path = std::env::temp_dir().join("tic_tac_toe_test_load_entry_count_overflow.tttb");
+    let mut bytes: Vec<u8> = FILE_MAGIC.to_vec();
+    bytes.push(FILE_VERSION);
+    bytes.extend_from_slice(&0u16.to_le_bytes());
+    bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+    fs::write(&path, bytes).unwrap();
+
+    let result = Tablebase::load(&path);
+    fs::remove_file(&path).unwrap();
+    result.unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Tablebase can not be solved with Gravity")]
+fn test_solve_gravity() {
+    let _t = Tablebase::solve("X,3,gravity=On").unwrap();
+}
+
+#[test]
+fn test_solve_blocked_cells() {
+    let tablebase = Tablebase::solve("X,3,blocked=1").unwrap();
+
+    // the blocked corner breaks all symmetries but the diagonal through it
+    let mut g = Game::new("X,3,blocked=1").unwrap();
+    g.play_move("2").unwrap();
+    let best_moves = tablebase.get_best_moves(&g);
+    assert!(!best_moves.is_empty());
+    assert!(best_moves.iter().all(|m| g.legal_moves().contains(m)));
+
+    let best_move = tablebase.get_best_move(&g).unwrap();
+    assert!(best_moves.contains(&best_move));
+}
+
+#[test]
+fn test_tablebase_lookup_4x4() {
+    use crate::analysis::get_hint;
+    use crate::players::minimax_player::MinimaxPlayer;
+    use crate::players::Player;
+    use crate::search::score_moves;
+    use std::sync::Arc;
+
+    // 9 playable cells in an L shape, small enough to solve
+    let settings_str = "X,4,win_length=3,blocked=1 2 3 5 9 13 16";
+    let tablebase = Arc::new(Tablebase::solve(settings_str).unwrap());
+
+    let mut g = Game::new(settings_str).unwrap();
+    g.set_tablebase(tablebase.clone()).unwrap();
+    g.play_move("4").unwrap();
+    g.play_move("6").unwrap();
+    g.play_move("8").unwrap();
+
+    // O has to block the column
+    let mut table_scores = tablebase.score_moves(&g).unwrap();
+    let mut search_scores = score_moves(&g, 9);
+    table_scores.sort();
+    search_scores.sort();
+    assert_eq!(table_scores, search_scores);
+
+    let hint = get_hint(&g).unwrap();
+    assert_eq!(hint.best_moves, vec!["12"]);
+    assert!(hint.exact);
+    assert_eq!(MinimaxPlayer::new(None).choose_move(&g), "12");
+}
+
+#[test]
+#[should_panic(expected = "Tablebase was solved for other settings")]
+fn test_set_tablebase_other_settings() {
+    let tablebase = Tablebase::solve("X,3").unwrap();
+    let mut g = Game::new("O,3").unwrap();
+    g.set_tablebase(std::sync::Arc::new(tablebase)).unwrap();
+}
@@ -1,101 +1,99 @@
 // NOTE 06: Calculation function that serves as a helper to the Game module.
 
-use crate::types::board::Board;
+use crate::types::board::{Board, CellGrid};
+use crate::types::board_dimension::BoardDimension;
+use crate::types::outcome::{DrawReason, Outcome, WinReason};
 use crate::types::player_mark::PlayerMark;
+#[cfg(any(test, feature = "bench"))]
+use enum_iterator::IntoEnumIterator;
 
-pub fn calculate_result(board: &Board) -> (bool, Option<String>) {
-    let board_dimension = board.dimension.get_value();
-
-    // Full row
-    for row_idx in 0..board_dimension {
-        let mut all_in_row_x = true;
-        let mut all_in_row_o = true;
-        for col_idx in 0..board_dimension {
-            let cell_value = board.cells[row_idx as usize][col_idx as usize]
-                .get_value()
-                .clone();
-            if cell_value != Some(PlayerMark::X) {
-                all_in_row_x = false;
-            }
-            if cell_value != Some(PlayerMark::O) {
-                all_in_row_o = false;
-            }
-        }
-        if all_in_row_x {
-            return (true, Some(PlayerMark::X.to_string()));
-        }
-        if all_in_row_o {
-            return (true, Some(PlayerMark::O.to_string()));
+// Incremental version used by Game: only lines through the cell just played can
+// have been completed, and the empty cell counter tells if the board is full
+pub fn calculate_result_after_move(
+    board: &Board,
+    row_idx: usize,
+    col_idx: usize,
+    misere: bool,
+) -> Outcome {
+    if let Some(pm) = board.get_cell(row_idx, col_idx) {
+        let marks = board.get_marks(&pm);
+        if let Some(mask) = board
+            .get_win_masks_through(row_idx, col_idx)
+            .iter()
+            .find(|mask| marks & *mask == **mask)
+        {
+            return get_line_outcome(pm, get_mask_cell_numbers(*mask), misere);
         }
     }
 
-    // Full column
-    for col_idx in 0..board_dimension {
-        let mut all_in_col_x = true;
-        let mut all_in_col_o = true;
-        for row_idx in 0..board_dimension {
-            let cell_value = board.cells[row_idx as usize][col_idx as usize]
-                .get_value()
-                .clone();
-            if cell_value != Some(PlayerMark::X) {
-                all_in_col_x = false;
-            }
-            if cell_value != Some(PlayerMark::O) {
-                all_in_col_o = false;
-            }
-        }
-        if all_in_col_x {
-            return (true, Some(PlayerMark::X.to_string()));
-        }
-        if all_in_col_o {
-            return (true, Some(PlayerMark::O.to_string()));
-        }
+    if board.is_full() {
+        return get_board_full_outcome();
     }
 
-    // Diagonals
-    let mut all_in_left_diagonal_x = true;
-    let mut all_in_right_diagonal_x = true;
-    let mut all_in_left_diagonal_o = true;
-    let mut all_in_right_diagonal_o = true;
-    for i in 0..board_dimension {
-        let cell_value_left_diagonal = board.cells[i as usize][i as usize].get_value().clone();
-        let cell_value_right_diagonal = board.cells[i as usize][(board_dimension - 1 - i) as usize]
-            .get_value()
-            .clone();
-
-        if cell_value_left_diagonal != Some(PlayerMark::X) {
-            all_in_left_diagonal_x = false;
-        }
-        if cell_value_right_diagonal != Some(PlayerMark::X) {
-            all_in_right_diagonal_x = false;
-        }
-        if cell_value_left_diagonal != Some(PlayerMark::O) {
-            all_in_left_diagonal_o = false;
-        }
-        if cell_value_right_diagonal != Some(PlayerMark::O) {
-            all_in_right_diagonal_o = false;
+    Outcome::InProgress
+}
+
+// No winning line holds only marks of one of the players still in the game,
+// nobody can win anymore
+pub fn is_dead_position(board: &Board, players: &[PlayerMark]) -> bool {
+    let occupied = board.get_occupied();
+
+    board.get_win_masks().iter().all(|mask| {
+        !players
+            .iter()
+            .any(|pm| board.get_marks(pm) & mask == occupied & mask)
+    })
+}
+
+// Bitboard version: a mark wins when all bits of a win mask are set,
+// with the misere rule it loses instead
+#[cfg(any(test, feature = "bench"))]
+pub fn calculate_result(board: &Board, misere: bool) -> Outcome {
+    for pm in PlayerMark::into_enum_iter() {
+        let marks = board.get_marks(&pm);
+        if let Some(mask) = board
+            .get_win_masks()
+            .iter()
+            .find(|mask| marks & *mask == **mask)
+        {
+            return get_line_outcome(pm, get_mask_cell_numbers(*mask), misere);
         }
     }
-    if all_in_left_diagonal_x {
-        return (true, Some(PlayerMark::X.to_string()));
-    }
-    if all_in_right_diagonal_x {
-        return (true, Some(PlayerMark::X.to_string()));
-    }
-    if all_in_left_diagonal_o {
-        return (true, Some(PlayerMark::O.to_string()));
+
+    if board.is_full() {
+        return get_board_full_outcome();
     }
-    if all_in_right_diagonal_o {
-        return (true, Some(PlayerMark::O.to_string()));
+
+    Outcome::InProgress
+}
+
+// Full scan of rows, columns and diagonals, works on any board representation.
+// Reference for the bitboard version in tests and benchmarks, standard rules only.
+#[cfg(any(test, feature = "bench"))]
+pub fn calculate_result_by_scan<B: CellGrid>(board: &B) -> Outcome {
+    let board_width = board.get_dimension().get_width();
+    let cell_number =
+        |(row_idx, col_idx): &(usize, usize)| *row_idx as u8 * board_width + *col_idx as u8 + 1;
+
+    // Full line
+    let dimension = board.get_dimension();
+    for line in get_winning_lines(dimension, dimension.get_line_length()) {
+        for pm in PlayerMark::into_enum_iter() {
+            if line
+                .iter()
+                .all(|(row_idx, col_idx)| board.get_cell(*row_idx, *col_idx) == Some(pm.clone()))
+            {
+                return get_line_outcome(pm, line.iter().map(cell_number).collect(), false);
+            }
+        }
     }
 
     // All cells are filled
     let mut all_filled = true;
-    'outer: for row_idx in 0..board_dimension {
-        for col_idx in 0..board_dimension {
-            let cell_value = board.cells[row_idx as usize][col_idx as usize]
-                .get_value()
-                .clone();
+    'outer: for row_idx in 0..board.get_dimension().get_height() {
+        for col_idx in 0..board_width {
+            let cell_value = board.get_cell(row_idx as usize, col_idx as usize);
+            #[allow(clippy::partialeq_to_none)]
             if cell_value == None {
                 all_filled = false;
                 break 'outer;
@@ -103,11 +101,130 @@ pub fn calculate_result(board: &Board) -> (bool, Option<String>) {
         }
     }
     if all_filled {
-        return (true, Some(String::from("Tied")));
+        return get_board_full_outcome();
     }
 
     // Not finished
-    (false, None)
+    Outcome::InProgress
+}
+
+// The mark that completed the line wins, or loses with the misere rule
+fn get_line_outcome(player_mark: PlayerMark, line: Vec<u8>, misere: bool) -> Outcome {
+    if misere {
+        return Outcome::Win {
+            winner: player_mark.get_opponent(),
+            reason: WinReason::Misere,
+            line,
+        };
+    }
+    Outcome::Win {
+        winner: player_mark,
+        reason: WinReason::Line,
+        line,
+    }
+}
+
+fn get_board_full_outcome() -> Outcome {
+    Outcome::Draw {
+        reason: DrawReason::BoardFull,
+    }
+}
+
+// Cell numbers (1-based, as used for moves) of all bits set in the mask
+fn get_mask_cell_numbers(mask: u64) -> Vec<u8> {
+    (0..u64::BITS as u8)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .map(|bit| bit + 1)
+        .collect()
+}
+
+// All rows, columns and diagonals as lists of (row_idx, col_idx). Lines shorter than
+// a side are taken at every offset that fits.
+pub fn get_winning_lines(
+    board_dimension: &BoardDimension,
+    line_length: u8,
+) -> Vec<Vec<(usize, usize)>> {
+    let width = board_dimension.get_width() as usize;
+    let height = board_dimension.get_height() as usize;
+    let length = line_length as usize;
+    let mut lines: Vec<Vec<(usize, usize)>> = vec![];
+
+    for row_idx in 0..height {
+        for start in 0..=width - length {
+            lines.push(
+                (start..start + length)
+                    .map(|col_idx| (row_idx, col_idx))
+                    .collect(),
+            );
+        }
+    }
+    for col_idx in 0..width {
+        for start in 0..=height - length {
+            lines.push(
+                (start..start + length)
+                    .map(|row_idx| (row_idx, col_idx))
+                    .collect(),
+            );
+        }
+    }
+    for row_start in 0..=height - length {
+        for col_start in 0..=width - length {
+            lines.push(
+                (0..length)
+                    .map(|i| (row_start + i, col_start + i))
+                    .collect(),
+            );
+        }
+    }
+    for row_start in 0..=height - length {
+        for col_start in 0..=width - length {
+            lines.push(
+                (0..length)
+                    .map(|i| (row_start + i, col_start + length - 1 - i))
+                    .collect(),
+            );
+        }
+    }
+
+    lines
+}
+
+// With the wrap rule lines continue across the edges, so every cell starts a line in
+// each direction. On a side as long as the line the same cells come up from several
+// starts, they are kept once.
+pub fn get_wrapped_winning_lines(
+    board_dimension: &BoardDimension,
+    line_length: u8,
+) -> Vec<Vec<(usize, usize)>> {
+    let width = board_dimension.get_width() as usize;
+    let height = board_dimension.get_height() as usize;
+    let length = line_length as usize;
+    let mut lines: Vec<Vec<(usize, usize)>> = vec![];
+    let mut seen: Vec<Vec<(usize, usize)>> = vec![];
+
+    // rows, columns, diagonals and anti-diagonals as steps that stay on the board
+    for (row_step, col_step) in [(0, 1), (1, 0), (1, 1), (1, width - 1)] {
+        for row_start in 0..height {
+            for col_start in 0..width {
+                let line: Vec<(usize, usize)> = (0..length)
+                    .map(|i| {
+                        (
+                            (row_start + i * row_step) % height,
+                            (col_start + i * col_step) % width,
+                        )
+                    })
+                    .collect();
+                let mut cells = line.clone();
+                cells.sort();
+                if !seen.contains(&cells) {
+                    seen.push(cells);
+                    lines.push(line);
+                }
+            }
+        }
+    }
+
+    lines
 }
 
 // NOTE: Tests are integrated in game module tests (test_detect_finished)
@@ -0,0 +1,183 @@
+// NOTE 17: Chess clock of a Game. Only the player on move loses time,
+//          a move stops their clock and starts the opponent's one.
+
+use crate::types::clock::Clock;
+use crate::types::player_mark::PlayerMark;
+use crate::types::time_control::TimeControl;
+use enum_iterator::IntoEnumIterator;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Timer {
+    time_control: TimeControl,
+    clock: Arc<dyn Clock>,
+    // Indexed by PlayerMark, time left at the start of the player's turn
+    remaining: Vec<Duration>,
+    turn_started: Duration,
+    // No clock runs before the start or the first move, nor after the game is over
+    started: bool,
+    stopped: bool,
+}
+
+impl Timer {
+    pub fn new(time_control: TimeControl, clock: Arc<dyn Clock>) -> Timer {
+        let initial = match &time_control {
+            TimeControl::Total { total, .. } => *total,
+            TimeControl::PerMove(per_move) => *per_move,
+        };
+        Timer {
+            remaining: PlayerMark::into_enum_iter().map(|_| initial).collect(),
+            turn_started: clock.now(),
+            time_control,
+            clock,
+            started: false,
+            stopped: false,
+        }
+    }
+
+    // Starts the clock of the player on move, setting up the players costs no time
+    pub fn start(&mut self) {
+        if !self.started {
+            self.turn_started = self.clock.now();
+            self.started = true;
+        }
+    }
+
+    // The clock of the player on move is running from the start until the timer is stopped
+    pub fn get_remaining(&self, player_mark: &PlayerMark, on_move: &PlayerMark) -> Duration {
+        let remaining = self.remaining[player_mark.get_index()];
+        if player_mark == on_move && self.started && !self.stopped {
+            remaining.saturating_sub(self.get_elapsed())
+        } else {
+            remaining
+        }
+    }
+
+    pub fn is_out_of_time(&self, on_move: &PlayerMark) -> bool {
+        self.get_remaining(on_move, on_move).is_zero()
+    }
+
+    // Called after the player on move played, starts the opponent's turn.
+    // A first move played before the start costs no time.
+    pub fn switch(&mut self, moved: &PlayerMark) {
+        if let TimeControl::Total { increment, .. } = &self.time_control {
+            let remaining = self.get_remaining(moved, moved) + *increment;
+            self.remaining[moved.get_index()] = remaining;
+        }
+        self.turn_started = self.clock.now();
+        self.started = true;
+    }
+
+    // Called when the player on move swaps sides: the time used so far is theirs, then
+    // the players exchange marks together with the time left of each mark
+    pub fn swap(&mut self, on_move: &PlayerMark) {
+        if let TimeControl::Total { .. } = &self.time_control {
+            self.remaining[on_move.get_index()] = self.get_remaining(on_move, on_move);
+        }
+        self.remaining
+            .swap(on_move.get_index(), on_move.get_opponent().get_index());
+        self.turn_started = self.clock.now();
+    }
+
+    // Called when the game is over, the time left of the player on move is kept
+    pub fn stop(&mut self, on_move: &PlayerMark) {
+        if !self.stopped {
+            self.remaining[on_move.get_index()] = self.get_remaining(on_move, on_move);
+            self.stopped = true;
+        }
+    }
+
+    // Time the player on move can spend on this move, if the rest of the game is
+    // played at the same pace
+    pub fn get_budget(&self, on_move: &PlayerMark, moves_left: u32) -> Duration {
+        let remaining = self.get_remaining(on_move, on_move);
+        match &self.time_control {
+            TimeControl::Total { increment, .. } => {
+                (remaining / moves_left.max(1) + *increment).min(remaining)
+            }
+            TimeControl::PerMove(_) => remaining,
+        }
+    }
+
+    fn get_elapsed(&self) -> Duration {
+        self.clock.now().saturating_sub(self.turn_started)
+    }
+}
+
+#[test]
+fn test_total_with_increment() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let time_control = TimeControl::Total {
+        total: Duration::from_secs(10),
+        increment: Duration::from_secs(2),
+    };
+    let mut timer = Timer::new(time_control, clock.clone());
+    timer.start();
+
+    clock.advance(Duration::from_secs(3));
+    assert_eq!(
+        timer.get_remaining(&PlayerMark::X, &PlayerMark::X),
+        Duration::from_secs(7)
+    );
+    assert_eq!(
+        timer.get_remaining(&PlayerMark::O, &PlayerMark::X),
+        Duration::from_secs(10)
+    );
+
+    timer.switch(&PlayerMark::X);
+    clock.advance(Duration::from_secs(4));
+    assert_eq!(
+        timer.get_remaining(&PlayerMark::X, &PlayerMark::O),
+        Duration::from_secs(9)
+    );
+    assert_eq!(
+        timer.get_remaining(&PlayerMark::O, &PlayerMark::O),
+        Duration::from_secs(6)
+    );
+    assert_eq!(timer.get_budget(&PlayerMark::O, 3), Duration::from_secs(4));
+
+    clock.advance(Duration::from_secs(6));
+    assert!(timer.is_out_of_time(&PlayerMark::O));
+}
+
+#[test]
+fn test_per_move() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut timer = Timer::new(TimeControl::PerMove(Duration::from_secs(5)), clock.clone());
+    timer.start();
+
+    clock.advance(Duration::from_secs(4));
+    assert!(!timer.is_out_of_time(&PlayerMark::X));
+    timer.switch(&PlayerMark::X);
+
+    // unused time is not carried over
+    assert_eq!(
+        timer.get_remaining(&PlayerMark::X, &PlayerMark::O),
+        Duration::from_secs(5)
+    );
+    clock.advance(Duration::from_secs(5));
+    assert!(timer.is_out_of_time(&PlayerMark::O));
+}
+
+#[test]
+fn test_stop() {
+    use crate::types::clock::ManualClock;
+
+    let clock = Arc::new(ManualClock::new());
+    let mut timer = Timer::new(TimeControl::PerMove(Duration::from_secs(5)), clock.clone());
+    timer.start();
+
+    clock.advance(Duration::from_secs(2));
+    timer.stop(&PlayerMark::X);
+    clock.advance(Duration::from_secs(10));
+    assert_eq!(
+        timer.get_remaining(&PlayerMark::X, &PlayerMark::X),
+        Duration::from_secs(3)
+    );
+    assert!(!timer.is_out_of_time(&PlayerMark::X));
+}
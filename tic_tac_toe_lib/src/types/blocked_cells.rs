@@ -0,0 +1,169 @@
+// Cells of the board nobody can play, given as cell numbers like '1 5 9' or drawn at
+// random as 'random <count> <seed>'. The same seed always blocks the same cells.
+// It can be created only through parse.
+
+use crate::types::board::get_split_mix_value;
+use crate::types::board_dimension::BoardDimension;
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BlockedCells(Vec<u8>);
+
+const OFF: &str = "Off";
+const RANDOM: &str = "random";
+
+impl BlockedCells {
+    // Counted from 1, in ascending order. Game reads the blocked cells from the board.
+    #[cfg(test)]
+    pub fn get_cell_numbers(&self) -> &[u8] {
+        &self.0
+    }
+
+    // Bit index is the cell number minus one, like in Board
+    pub fn get_mask(&self) -> u64 {
+        self.0
+            .iter()
+            .fold(0, |mask, cell_number| mask | 1 << (cell_number - 1))
+    }
+
+    // With gravity no mark can fall past a blocked cell, so the cells below it
+    // can not be played either
+    pub fn get_mask_with_gravity(&self, board_dimension: &BoardDimension) -> u64 {
+        let width = board_dimension.get_width() as u32;
+        let cell_count = board_dimension.get_cell_count() as u32;
+        (0..cell_count - width).fold(self.get_mask(), |mask, bit_idx| match mask & 1 << bit_idx {
+            0 => mask,
+            _ => mask | 1 << (bit_idx + width),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    vec![
+        String::from(OFF),
+        String::from("<cells separated by space>"),
+        String::from("random <count> <seed>"),
+    ]
+}
+
+pub fn get_default() -> BlockedCells {
+    BlockedCells(vec![])
+}
+
+pub fn get_default_as_str() -> String {
+    String::from(OFF)
+}
+
+// Cells are checked against the board, so the dimension has to be known
+pub fn parse(board_dimension: &BoardDimension, s: &str) -> Result<BlockedCells, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() || str.to_lowercase() == OFF.to_lowercase() {
+        return Ok(get_default());
+    }
+
+    let cell_count = board_dimension.get_cell_count();
+    let parts: Vec<&str> = str.split_whitespace().collect();
+    let mut cell_numbers: Vec<u8> = if parts[0].to_lowercase() == RANDOM {
+        let (count, seed) = match parts[1..] {
+            [count, seed] => (count, seed),
+            _ => bail!(
+                "Blocked Cells '{}' must give the count and the seed, for example random 3 42",
+                s
+            ),
+        };
+        let count = match count.parse::<u8>() {
+            Ok(val) => val,
+            Err(_) => bail!("Blocked Cell Count '{}' is not a number", count),
+        };
+        let seed = match seed.parse::<u64>() {
+            Ok(val) => val,
+            Err(_) => bail!("Seed '{}' is not a number", seed),
+        };
+        if count >= cell_count {
+            bail!("Blocked Cells must leave some cells to play");
+        }
+
+        // first cells of a shuffle, drawn one by one from the cells not taken yet
+        let mut all_cells: Vec<u8> = (1..=cell_count).collect();
+        for idx in 0..count as usize {
+            let cells_left = (all_cells.len() - idx) as u64;
+            let drawn = idx + (get_split_mix_value(seed, idx as u64) % cells_left) as usize;
+            all_cells.swap(idx, drawn);
+        }
+        all_cells.truncate(count as usize);
+        all_cells
+    } else {
+        let mut cell_numbers: Vec<u8> = vec![];
+        for part in parts {
+            let cell_number = match part.parse::<u8>() {
+                Ok(val) => val,
+                Err(_) => bail!("Blocked Cell '{}' is not a number", part),
+            };
+            if cell_number < 1 {
+                bail!("Blocked Cell can not be less than 1");
+            }
+            if cell_number > cell_count {
+                bail!("Blocked Cell can not be greater than {}", cell_count);
+            }
+            if !cell_numbers.contains(&cell_number) {
+                cell_numbers.push(cell_number);
+            }
+        }
+        if cell_numbers.len() >= cell_count as usize {
+            bail!("Blocked Cells must leave some cells to play");
+        }
+        cell_numbers
+    };
+    cell_numbers.sort();
+
+    Ok(BlockedCells(cell_numbers))
+}
+
+#[cfg(test)]
+use crate::types::board_dimension::parse as parse_board_dimension;
+
+#[test]
+fn test_parse_cells() {
+    let board_dimension = parse_board_dimension("3").unwrap();
+    assert_eq!(parse(&board_dimension, "off").unwrap(), get_default());
+
+    let blocked_cells = parse(&board_dimension, " 9 1  5 ").unwrap();
+    assert_eq!(blocked_cells.get_cell_numbers(), [1, 5, 9]);
+    assert_eq!(blocked_cells.get_mask(), 0b100_010_001);
+}
+
+#[test]
+fn test_parse_random_same_seed_same_cells() {
+    let board_dimension = parse_board_dimension("5").unwrap();
+    let blocked_cells = parse(&board_dimension, "random 4 42").unwrap();
+    // drawn with SplitMix64, the cells must never change for a seed
+    assert_eq!(blocked_cells.get_cell_numbers(), vec![10, 14, 16, 21]);
+    assert_eq!(
+        parse(&board_dimension, "Random 4 42").unwrap(),
+        blocked_cells
+    );
+}
+
+#[test]
+#[should_panic(expected = "Blocked Cell can not be greater than 9")]
+fn test_parse_cell_outside_board() {
+    let _bc = parse(&parse_board_dimension("3").unwrap(), "10").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Blocked Cells 'random 3' must give the count and the seed")]
+fn test_parse_random_without_seed() {
+    let _bc = parse(&parse_board_dimension("3").unwrap(), "random 3").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Blocked Cells must leave some cells to play")]
+fn test_parse_all_cells() {
+    let _bc = parse(&parse_board_dimension("3").unwrap(), "random 9 1").unwrap();
+}
@@ -1,21 +1,474 @@
-// NOTE 03: Double vector with run-time size.
+// NOTE 03: Bitboard with run-time size. One bitmask per mark, bit index is
+//          row_idx * width + col_idx. Winning lines are precomputed as masks too.
 
+use crate::state_calculation::{get_winning_lines, get_wrapped_winning_lines};
 use crate::types::board_dimension::BoardDimension;
-use crate::types::cell_value::CellValue;
+use crate::types::player_mark::PlayerMark;
+use crate::types::symmetry::Symmetry;
+use enum_iterator::IntoEnumIterator;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, OnceLock};
+
+// Win masks depend only on the dimension, the line length, the blocked cells and
+// the wrap rule, so they are computed once per combination. Shared by all threads,
+// boards and games can be moved between them.
+static WIN_MASKS: OnceLock<Mutex<HashMap<WinMasksKey, Arc<WinMasks>>>> = OnceLock::new();
+
+type WinMasksKey = (BoardDimension, u8, u64, bool);
+
+// Number of PlayerMark values, one bitmask each
+const MARK_COUNT: usize = 4;
 
 #[derive(Debug)]
+struct WinMasks {
+    all: Vec<u64>,
+    // Masks of the lines passing through each cell, indexed by bit index
+    by_cell: Vec<Vec<u64>>,
+}
+
+// Cell access shared by all board representations
+pub trait CellGrid {
+    #[cfg(any(test, feature = "bench"))]
+    fn get_dimension(&self) -> &BoardDimension;
+    fn get_cell(&self, row_idx: usize, col_idx: usize) -> Option<PlayerMark>;
+    fn set_cell(&mut self, row_idx: usize, col_idx: usize, value: Option<PlayerMark>);
+}
+
+#[derive(Debug, Clone)]
 pub struct Board {
     pub dimension: BoardDimension,
-    pub cells: Vec<Vec<CellValue>>,
+    marks: [u64; MARK_COUNT],
+    // Cells nobody can play, lines through them do not count
+    blocked: u64,
+    empty_cells: u32,
+    win_masks: Arc<WinMasks>,
 }
 
 impl Board {
     pub fn new(dimension: BoardDimension) -> Board {
-        let vec_size = dimension.get_value() as usize;
+        let line_length = dimension.get_line_length();
+        Board::with_line_length(dimension, line_length)
+    }
+
+    // Lines shorter than the sides, like five in a row on 6x6
+    pub fn with_line_length(dimension: BoardDimension, line_length: u8) -> Board {
+        Board::build(dimension, line_length, 0, false)
+    }
+
+    // Blocked cells are given as a mask with the same bit index as the marks,
+    // with wrap the lines continue across the edges
+    pub fn with_rules(
+        dimension: BoardDimension,
+        line_length: u8,
+        blocked: u64,
+        wrap: bool,
+    ) -> Board {
+        Board::build(dimension, line_length, blocked, wrap)
+    }
+
+    fn build(dimension: BoardDimension, line_length: u8, blocked: u64, wrap: bool) -> Board {
+        let win_masks = WIN_MASKS
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap()
+            .entry((dimension.clone(), line_length, blocked, wrap))
+            .or_insert_with(|| Arc::new(get_win_masks(&dimension, line_length, blocked, wrap)))
+            .clone();
 
         Board {
+            empty_cells: dimension.get_cell_count() as u32 - blocked.count_ones(),
             dimension,
-            cells: vec![vec![CellValue::new(None); vec_size]; vec_size],
+            marks: [0; MARK_COUNT],
+            blocked,
+            win_masks,
+        }
+    }
+
+    pub fn is_blocked(&self, row_idx: usize, col_idx: usize) -> bool {
+        self.blocked & self.get_bit(row_idx, col_idx) != 0
+    }
+
+    pub fn get_marks(&self, player_mark: &PlayerMark) -> u64 {
+        self.marks[player_mark.get_index()]
+    }
+
+    pub fn get_occupied(&self) -> u64 {
+        self.marks.iter().fold(0, |all, marks| all | marks)
+    }
+
+    pub fn get_win_masks(&self) -> &[u64] {
+        &self.win_masks.all
+    }
+
+    pub fn get_win_masks_through(&self, row_idx: usize, col_idx: usize) -> &[u64] {
+        &self.win_masks.by_cell[row_idx * self.dimension.get_width() as usize + col_idx]
+    }
+
+    // Cells still to be played, blocked cells do not count
+    pub fn get_empty_cell_count(&self) -> u32 {
+        self.empty_cells
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.empty_cells == 0
+    }
+
+    // Smallest bitboards over all symmetries of the board shape, equal for all
+    // equivalent boards. Symmetry maps this board to the canonical one.
+    pub fn canonical_key(&self) -> (CanonicalKey, Symmetry) {
+        Symmetry::get_all(&self.dimension)
+            .into_iter()
+            .map(|symmetry| (self.get_key_under(symmetry), symmetry))
+            .min_by_key(|(key, _)| *key)
+            .unwrap()
+    }
+
+    // Cell on this board for a cell given in the canonical frame returned by canonical_key
+    pub fn get_real_cell(&self, symmetry: Symmetry, cell: (usize, usize)) -> (usize, usize) {
+        symmetry.get_inverse().apply(&self.dimension, cell)
+    }
+
+    // Bitboards of the board mapped by the symmetry. Blocked cells are kept like
+    // one more mark, so only symmetries that keep them in place give equal keys.
+    fn get_key_under(&self, symmetry: Symmetry) -> CanonicalKey {
+        let mut marks = [0; MARK_COUNT];
+        for (idx, bits) in self.marks.iter().enumerate() {
+            marks[idx] = self.get_bits_under(*bits, symmetry);
+        }
+        CanonicalKey {
+            marks,
+            blocked: self.get_bits_under(self.blocked, symmetry),
+        }
+    }
+
+    fn get_bits_under(&self, mut bits: u64, symmetry: Symmetry) -> u64 {
+        let board_width = self.dimension.get_width() as usize;
+
+        let mut mapped: u64 = 0;
+        while bits != 0 {
+            let cell_idx = bits.trailing_zeros() as usize;
+            bits &= bits - 1;
+
+            let (r, c) = symmetry.apply(
+                &self.dimension,
+                (cell_idx / board_width, cell_idx % board_width),
+            );
+            mapped |= 1 << (r * board_width + c);
+        }
+        mapped
+    }
+
+    fn get_bit(&self, row_idx: usize, col_idx: usize) -> u64 {
+        1 << (row_idx * self.dimension.get_width() as usize + col_idx)
+    }
+}
+
+impl CellGrid for Board {
+    #[cfg(any(test, feature = "bench"))]
+    fn get_dimension(&self) -> &BoardDimension {
+        &self.dimension
+    }
+
+    fn get_cell(&self, row_idx: usize, col_idx: usize) -> Option<PlayerMark> {
+        let bit = self.get_bit(row_idx, col_idx);
+        PlayerMark::into_enum_iter().find(|pm| self.get_marks(pm) & bit != 0)
+    }
+
+    fn set_cell(&mut self, row_idx: usize, col_idx: usize, value: Option<PlayerMark>) {
+        let bit = self.get_bit(row_idx, col_idx);
+        if self.get_occupied() & bit != 0 {
+            self.empty_cells += 1;
+        }
+        for marks in self.marks.iter_mut() {
+            *marks &= !bit;
+        }
+        if let Some(pm) = value {
+            self.marks[pm.get_index()] |= bit;
+            self.empty_cells -= 1;
+        }
+    }
+}
+
+// Win masks are derived from the dimension, line length and blocked cells, so they are
+// left out of comparisons
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.dimension == other.dimension
+            && self.marks == other.marks
+            && self.blocked == other.blocked
+    }
+}
+
+impl Eq for Board {}
+
+impl Hash for Board {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.dimension.hash(state);
+        self.marks.hash(state);
+        self.blocked.hash(state);
+    }
+}
+
+fn get_win_masks(
+    board_dimension: &BoardDimension,
+    line_length: u8,
+    blocked: u64,
+    wrap: bool,
+) -> WinMasks {
+    let board_width = board_dimension.get_width() as usize;
+    let lines = match wrap {
+        true => get_wrapped_winning_lines(board_dimension, line_length),
+        false => get_winning_lines(board_dimension, line_length),
+    };
+    let all: Vec<u64> = lines
+        .iter()
+        .map(|line| {
+            line.iter().fold(0, |mask, (row_idx, col_idx)| {
+                mask | 1 << (row_idx * board_width + col_idx)
+            })
+        })
+        .filter(|mask| mask & blocked == 0)
+        .collect();
+    let by_cell = (0..board_dimension.get_cell_count() as usize)
+        .map(|cell_idx| {
+            all.iter()
+                .filter(|mask| *mask & (1 << cell_idx) != 0)
+                .copied()
+                .collect()
+        })
+        .collect();
+
+    WinMasks { all, by_cell }
+}
+
+// Position in the canonical frame. Keys are compared by the bitboards themselves,
+// so two different positions never share a key, the Zobrist hash only feeds hash maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CanonicalKey {
+    marks: [u64; MARK_COUNT],
+    blocked: u64,
+}
+
+impl CanonicalKey {
+    // Size of a key in a file, every bitboard as 8 little endian bytes
+    pub const BYTE_COUNT: usize = 8 * (MARK_COUNT + 1);
+
+    pub fn to_bytes(self) -> Vec<u8> {
+        self.marks
+            .iter()
+            .chain([&self.blocked])
+            .flat_map(|bits| bits.to_le_bytes())
+            .collect()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> CanonicalKey {
+        let bits: Vec<u64> = bytes
+            .chunks(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        CanonicalKey {
+            marks: bits[..MARK_COUNT].try_into().unwrap(),
+            blocked: bits[MARK_COUNT],
+        }
+    }
+
+    // Zobrist hash: XOR of one random number per (cell, mark) pair on the board,
+    // blocked cells are hashed like one more mark
+    pub fn get_zobrist_hash(&self) -> u64 {
+        let mut hash: u64 = 0;
+        for (kind_idx, bits) in self.marks.iter().chain([&self.blocked]).enumerate() {
+            let mut bits = *bits;
+            while bits != 0 {
+                let cell_idx = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                hash ^= get_zobrist_value(cell_idx, kind_idx);
+            }
         }
+        hash
+    }
+}
+
+impl Hash for CanonicalKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.get_zobrist_hash());
     }
 }
+
+// Deterministic pseudo random number per (cell, mark or blocked), SplitMix64 of the pair index
+fn get_zobrist_value(cell_idx: usize, kind_idx: usize) -> u64 {
+    get_split_mix_value(0, (cell_idx * 8 + kind_idx) as u64)
+}
+
+// Number at the index of the SplitMix64 sequence started at the seed. A fixed algorithm,
+// so the numbers stay the same across versions of the rand crate.
+pub fn get_split_mix_value(seed: u64, index: u64) -> u64 {
+    let mut z = seed.wrapping_add(index.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+fn board_from_rows(rows: &[&str]) -> Board {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+    use crate::types::player_mark::parse as parse_player_mark;
+
+    let mut board = Board::new(parse_board_dimension(&rows.len().to_string()).unwrap());
+    for (row_idx, row) in rows.iter().enumerate() {
+        for (col_idx, c) in row.chars().enumerate() {
+            if c != '.' {
+                let pm = parse_player_mark(&c.to_string()).unwrap();
+                board.set_cell(row_idx, col_idx, Some(pm));
+            }
+        }
+    }
+    board
+}
+
+#[test]
+fn test_get_set_cell() {
+    let mut board = board_from_rows(&["X..", ".O.", "..."]);
+    assert_eq!(board.get_cell(0, 0), Some(PlayerMark::X));
+    assert_eq!(board.get_cell(1, 1), Some(PlayerMark::O));
+    assert_eq!(board.get_cell(2, 2), None);
+    assert_eq!(board.get_marks(&PlayerMark::X), 0b000_000_001);
+    assert_eq!(board.get_marks(&PlayerMark::O), 0b000_010_000);
+
+    board.set_cell(0, 0, Some(PlayerMark::O));
+    assert_eq!(board.get_cell(0, 0), Some(PlayerMark::O));
+    assert_eq!(board.get_marks(&PlayerMark::X), 0);
+
+    board.set_cell(0, 0, None);
+    assert_eq!(board.get_cell(0, 0), None);
+}
+
+#[test]
+fn test_win_masks() {
+    let board = board_from_rows(&["...", "...", "..."]);
+    let masks = board.get_win_masks();
+
+    assert_eq!(masks.len(), 8);
+    assert!(masks.contains(&0b000_000_111));
+    assert!(masks.contains(&0b001_001_001));
+    assert!(masks.contains(&0b100_010_001));
+    assert!(masks.contains(&0b001_010_100));
+}
+
+#[test]
+fn test_win_masks_through() {
+    let board = board_from_rows(&["...", "...", "..."]);
+
+    // Corner: row, column and one diagonal. Center: all four directions.
+    assert_eq!(board.get_win_masks_through(0, 0).len(), 3);
+    assert_eq!(board.get_win_masks_through(1, 1).len(), 4);
+    assert_eq!(board.get_win_masks_through(0, 1).len(), 2);
+    assert!(board
+        .get_win_masks_through(0, 1)
+        .iter()
+        .all(|mask| mask & 0b000_000_010 != 0));
+}
+
+#[test]
+fn test_is_full() {
+    assert!(!board_from_rows(&["XOX", "OXO", "OX."]).is_full());
+    assert!(board_from_rows(&["XOX", "OXO", "OXO"]).is_full());
+}
+
+#[test]
+fn test_empty_cells_counter() {
+    let mut board = board_from_rows(&["X..", "...", "..."]);
+    assert_eq!(board.empty_cells, 8);
+
+    // Overwriting a filled cell does not change the count
+    board.set_cell(0, 0, Some(PlayerMark::O));
+    assert_eq!(board.empty_cells, 8);
+
+    board.set_cell(0, 0, None);
+    board.set_cell(0, 0, None);
+    assert_eq!(board.empty_cells, 9);
+}
+
+#[test]
+fn test_get_hash_differs_by_mark_and_cell() {
+    let empty = board_from_rows(&["...", "...", "..."]);
+    let x_corner = board_from_rows(&["X..", "...", "..."]);
+    let o_corner = board_from_rows(&["O..", "...", "..."]);
+    let x_center = board_from_rows(&["...", ".X.", "..."]);
+
+    let get_hash = |board: &Board| board.get_key_under(Symmetry::Identity).get_zobrist_hash();
+    assert_eq!(get_hash(&empty), 0);
+    assert_ne!(get_hash(&x_corner), get_hash(&o_corner));
+    assert_ne!(get_hash(&x_corner), get_hash(&x_center));
+}
+
+#[test]
+fn test_canonical_key_bytes_round_trip() {
+    let board = board_from_rows(&["XO.", "..X", "O.."]);
+    let (key, _) = board.canonical_key();
+    let bytes = key.to_bytes();
+    assert_eq!(bytes.len(), CanonicalKey::BYTE_COUNT);
+    assert_eq!(CanonicalKey::from_bytes(&bytes), key);
+}
+
+#[test]
+fn test_canonical_key_equal_for_symmetric_boards() {
+    let boards = [
+        board_from_rows(&["XO.", "...", "..."]),
+        board_from_rows(&["..X", "..O", "..."]),
+        board_from_rows(&["...", "...", ".OX"]),
+        board_from_rows(&["X..", "O..", "..."]),
+    ];
+    let (key, _) = boards[0].canonical_key();
+    for board in &boards {
+        assert_eq!(board.canonical_key().0, key);
+    }
+
+    let other = board_from_rows(&["X.O", "...", "..."]);
+    assert_ne!(other.canonical_key().0, key);
+}
+
+#[test]
+fn test_from_canonical_cell() {
+    let board = board_from_rows(&["...", "..X", "..."]);
+    let (_, symmetry) = board.canonical_key();
+
+    // X is found again through the canonical frame
+    let canonical_cell = symmetry.apply(&board.dimension, (1, 2));
+    assert_eq!(board.get_real_cell(symmetry, canonical_cell), (1, 2));
+}
+
+#[test]
+fn test_mark_count() {
+    assert_eq!(PlayerMark::into_enum_iter().count(), MARK_COUNT);
+}
+
+#[test]
+fn test_blocked_cells() {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+
+    // the center is blocked, only the four lines along the edges are left
+    let board = Board::with_rules(parse_board_dimension("3").unwrap(), 3, 0b000_010_000, false);
+    assert!(board.is_blocked(1, 1));
+    assert!(!board.is_blocked(0, 0));
+    assert_eq!(board.get_win_masks().len(), 4);
+    assert!(board.get_win_masks_through(1, 1).is_empty());
+    assert_eq!(board.empty_cells, 8);
+
+    // blocking a corner breaks the symmetry of the corners
+    let corner = Board::with_rules(parse_board_dimension("3").unwrap(), 3, 0b000_000_001, false);
+    let mut a = corner.clone();
+    a.set_cell(0, 2, Some(PlayerMark::X));
+    let mut b = corner.clone();
+    b.set_cell(2, 2, Some(PlayerMark::X));
+    let mut c = corner;
+    c.set_cell(2, 0, Some(PlayerMark::X));
+    assert_eq!(a.canonical_key().0, c.canonical_key().0);
+    assert_ne!(a.canonical_key().0, b.canonical_key().0);
+}
+
+#[test]
+fn test_board_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Board>();
+}
@@ -0,0 +1,72 @@
+// Number of boards played at once, only Notakto uses more than one.
+// u8 type with constraints. It can be created only through parse.
+
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BoardCount(u8);
+
+const MIN_VALUE: u8 = 1;
+const MAX_VALUE: u8 = 5;
+const DEFAULT_VALUE: u8 = 1;
+
+impl BoardCount {
+    pub fn get_value(&self) -> u8 {
+        self.0
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    (MIN_VALUE..=MAX_VALUE).map(|i| i.to_string()).collect()
+}
+
+pub fn get_default() -> BoardCount {
+    BoardCount(DEFAULT_VALUE)
+}
+
+pub fn parse(s: &str) -> Result<BoardCount, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() {
+        return Ok(get_default());
+    }
+
+    let val = match str.parse::<u8>() {
+        Ok(val) => val,
+        Err(_) => bail!("Board Count '{}' is not a number", s),
+    };
+    if val < MIN_VALUE {
+        bail!("Board Count can not be less than {}", MIN_VALUE);
+    }
+    if val > MAX_VALUE {
+        bail!("Board Count can not be greater than {}", MAX_VALUE);
+    }
+
+    Ok(BoardCount(val))
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("").unwrap(), get_default());
+    assert_eq!(parse(" 3 ").unwrap().get_value(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Board Count can not be greater than 5")]
+fn test_parse_greater_than_max() {
+    let _bc = parse("6").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Board Count can not be less than 1")]
+fn test_parse_less_than_min() {
+    let _bc = parse("0").unwrap();
+}
+
+#[test]
+fn test_business_rules() {
+    assert_eq!(MIN_VALUE, 1);
+    assert_eq!(MAX_VALUE, 5);
+    assert_eq!(DEFAULT_VALUE, 1);
+}
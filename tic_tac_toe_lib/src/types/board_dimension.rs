@@ -1,18 +1,43 @@
-// NOTE 02: u8 type with constraints. It can be created only through parse.
+// NOTE 02: Width and height with constraints. It can be created only through parse,
+//          which gives a square board, and parse_sides for rectangular boards.
 
 use crate::types::errors::TicTacToeError;
 use anyhow::bail;
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct BoardDimension(u8);
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct BoardDimension {
+    width: u8,
+    height: u8,
+}
 
 const MIN_VALUE: u8 = 3;
 const MAX_VALUE: u8 = 5;
 const DEFAULT_VALUE: u8 = 3;
+// Sides given separately may be longer, a bitboard holds up to 8x8 cells
+const MAX_SIDE_VALUE: u8 = 8;
+// A side given as this value keeps the side of the board size
+const SIDE_DEFAULT: &str = "Board Size";
 
 impl BoardDimension {
-    pub fn get_value(&self) -> u8 {
-        self.0
+    pub fn get_width(&self) -> u8 {
+        self.width
+    }
+
+    pub fn get_height(&self) -> u8 {
+        self.height
+    }
+
+    pub fn get_cell_count(&self) -> u8 {
+        self.width * self.height
+    }
+
+    // Lines as long as the shorter side win, unless a variant sets its own length
+    pub fn get_line_length(&self) -> u8 {
+        self.width.min(self.height)
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.width == self.height
     }
 }
 
@@ -26,15 +51,27 @@ pub fn get_all_as_vec_str() -> Vec<String> {
     vec_str.to_vec()
 }
 
+// Values for width and height given separately
+pub fn get_all_sides_as_vec_str() -> Vec<String> {
+    let mut vec_str = vec![get_default_side_as_str()];
+    vec_str.extend((MIN_VALUE..=MAX_SIDE_VALUE).map(|i| i.to_string()));
+    vec_str
+}
+
+pub fn get_default_side_as_str() -> String {
+    String::from(SIDE_DEFAULT)
+}
+
 pub fn get_default() -> BoardDimension {
-    BoardDimension(DEFAULT_VALUE)
+    get_square(DEFAULT_VALUE)
 }
 
 pub fn parse(s: &str) -> Result<BoardDimension, TicTacToeError> {
     let str = s.trim();
 
+    #[allow(clippy::len_zero)]
     if str.len() == 0 {
-        return Ok(BoardDimension(DEFAULT_VALUE));
+        return Ok(get_default());
     }
 
     let parse_result = str.parse::<u8>();
@@ -50,14 +87,65 @@ pub fn parse(s: &str) -> Result<BoardDimension, TicTacToeError> {
         bail!("Board Dimension can not be greater than {}", MAX_VALUE);
     }
 
-    Ok(BoardDimension(val))
+    Ok(get_square(val))
+}
+
+// Width and height replace the sides of the given board, a side not given keeps its size
+pub fn parse_sides(
+    board_dimension: &BoardDimension,
+    width: Option<&str>,
+    height: Option<&str>,
+) -> Result<BoardDimension, TicTacToeError> {
+    Ok(BoardDimension {
+        width: match width {
+            Some(s) if !is_default_side(s) => parse_side("Board Width", s)?,
+            _ => board_dimension.width,
+        },
+        height: match height {
+            Some(s) if !is_default_side(s) => parse_side("Board Height", s)?,
+            _ => board_dimension.height,
+        },
+    })
+}
+
+fn is_default_side(s: &str) -> bool {
+    s.trim().to_lowercase() == SIDE_DEFAULT.to_lowercase()
+}
+
+fn parse_side(name: &str, s: &str) -> Result<u8, TicTacToeError> {
+    let val = match s.trim().parse::<u8>() {
+        Ok(val) => val,
+        Err(_) => bail!("{} '{}' is not a number", name, s),
+    };
+
+    if val < MIN_VALUE {
+        bail!("{} can not be less than {}", name, MIN_VALUE);
+    }
+    if val > MAX_SIDE_VALUE {
+        bail!("{} can not be greater than {}", name, MAX_SIDE_VALUE);
+    }
+
+    Ok(val)
+}
+
+// Square board of a variant that sets its own size, up to the longest side
+pub fn get_fixed_square(side: u8) -> BoardDimension {
+    assert!((MIN_VALUE..=MAX_SIDE_VALUE).contains(&side));
+    get_square(side)
+}
+
+fn get_square(side: u8) -> BoardDimension {
+    BoardDimension {
+        width: side,
+        height: side,
+    }
 }
 
 #[test]
 fn test_parse_empty_return_default() {
-    assert_eq!(parse("").unwrap().get_value(), DEFAULT_VALUE);
-    assert_eq!(parse(" ").unwrap().get_value(), DEFAULT_VALUE);
-    assert_eq!(parse("\t").unwrap().get_value(), DEFAULT_VALUE);
+    assert_eq!(parse("").unwrap().get_width(), DEFAULT_VALUE);
+    assert_eq!(parse(" ").unwrap().get_width(), DEFAULT_VALUE);
+    assert_eq!(parse("\t").unwrap().get_width(), DEFAULT_VALUE);
 }
 
 #[test]
@@ -80,19 +168,42 @@ fn test_parse_greater_than_max() {
 
 #[test]
 fn test_parse_convert_min_value() {
-    let bd = parse(MIN_VALUE.to_string().as_str()).unwrap().get_value();
+    let bd = parse(MIN_VALUE.to_string().as_str()).unwrap().get_width();
     assert_eq!(bd, MIN_VALUE);
 }
 
 #[test]
 fn test_parse_convert_max_value() {
-    let bd = parse(MAX_VALUE.to_string().as_str()).unwrap().get_value();
+    let bd = parse(MAX_VALUE.to_string().as_str()).unwrap().get_width();
     assert_eq!(bd, MAX_VALUE);
 }
 
+#[test]
+fn test_parse_sides() {
+    let bd = parse_sides(&get_default(), Some(" 7 "), Some("6")).unwrap();
+    assert_eq!((bd.get_width(), bd.get_height()), (7, 6));
+    assert_eq!(bd.get_cell_count(), 42);
+    assert_eq!(bd.get_line_length(), 6);
+    assert!(!bd.is_square());
+
+    let bd = parse_sides(&parse("4").unwrap(), None, Some("3")).unwrap();
+    assert_eq!((bd.get_width(), bd.get_height()), (4, 3));
+
+    // the default value of the rules keeps the side of the board size
+    let bd = parse_sides(&parse("4").unwrap(), Some("Board Size"), Some("5")).unwrap();
+    assert_eq!((bd.get_width(), bd.get_height()), (4, 5));
+}
+
+#[test]
+#[should_panic(expected = "Board Height can not be greater than 8")]
+fn test_parse_sides_greater_than_max() {
+    let _bd = parse_sides(&get_default(), None, Some("9")).unwrap();
+}
+
 #[test]
 fn test_business_rules() {
     assert_eq!(MIN_VALUE, 3);
     assert_eq!(MAX_VALUE, 5);
     assert_eq!(DEFAULT_VALUE, 3);
+    assert_eq!(MAX_SIDE_VALUE, 8);
 }
@@ -1,3 +1,5 @@
+use crate::types::board::Board;
+#[cfg(test)]
 use crate::types::board_dimension::parse as parse_board_dimension;
 use crate::types::board_dimension::BoardDimension;
 use crate::types::errors::TicTacToeError;
@@ -9,9 +11,13 @@ pub struct BoardMove {
 }
 
 impl BoardMove {
+    pub fn get_value(&self) -> u8 {
+        self.value
+    }
+
     pub fn get_indices(&self) -> (u8, u8) {
-        let row_idx = (self.value - 1) / self.board_dimension.get_value();
-        let col_idx = (self.value - 1) % self.board_dimension.get_value();
+        let row_idx = (self.value - 1) / self.board_dimension.get_width();
+        let col_idx = (self.value - 1) % self.board_dimension.get_width();
         (row_idx, col_idx)
     }
 }
@@ -31,7 +37,7 @@ pub fn parse(board_dimension: &BoardDimension, s: &str) -> Result<BoardMove, Tic
         bail!("Move can not be less than {}", min_value);
     }
 
-    let max_value = board_dimension.get_value().pow(2);
+    let max_value = board_dimension.get_cell_count();
     if val > max_value {
         bail!("Move can not be greater than {}", max_value);
     }
@@ -42,6 +48,38 @@ pub fn parse(board_dimension: &BoardDimension, s: &str) -> Result<BoardMove, Tic
     })
 }
 
+// Cell that can be played on the board, blocked cells are rejected
+pub fn parse_playable(board: &Board, s: &str) -> Result<BoardMove, TicTacToeError> {
+    let board_move = parse(&board.dimension, s)?;
+    let (row_idx, col_idx) = board_move.get_indices();
+    if board.is_blocked(row_idx as usize, col_idx as usize) {
+        bail!("Cell {} is blocked", board_move.get_value());
+    }
+    Ok(board_move)
+}
+
+// Column form used when marks fall down, returns the column index
+pub fn parse_column(board_dimension: &BoardDimension, s: &str) -> Result<u8, TicTacToeError> {
+    let str = s.trim();
+
+    let val = match str.parse::<u8>() {
+        Ok(val) => val,
+        Err(_) => bail!("Column '{}' is not a number", s),
+    };
+
+    if val < 1 {
+        bail!("Column can not be less than 1");
+    }
+    if val > board_dimension.get_width() {
+        bail!(
+            "Column can not be greater than {}",
+            board_dimension.get_width()
+        );
+    }
+
+    Ok(val - 1)
+}
+
 #[test]
 fn test_get_indices() {
     let board_dimension = parse_board_dimension("3").unwrap();
@@ -62,6 +100,27 @@ fn test_get_indices() {
     assert_eq!(col_idx, 2);
 }
 
+#[test]
+fn test_get_indices_rectangular() {
+    use crate::types::board_dimension::parse_sides;
+
+    // 4 wide, 3 high: cell 5 starts the second row
+    let board_dimension =
+        parse_sides(&parse_board_dimension("3").unwrap(), Some("4"), None).unwrap();
+    assert_eq!(parse(&board_dimension, "5").unwrap().get_indices(), (1, 0));
+    assert_eq!(parse(&board_dimension, "12").unwrap().get_indices(), (2, 3));
+    assert!(parse(&board_dimension, "13").is_err());
+}
+
+#[test]
+#[should_panic(expected = "Cell 5 is blocked")]
+fn test_parse_playable_blocked() {
+    let board_dimension = parse_board_dimension("3").unwrap();
+    let board = Board::with_rules(board_dimension, 3, 0b000_010_000, false);
+    assert!(parse_playable(&board, "4").is_ok());
+    parse_playable(&board, "5").unwrap();
+}
+
 #[test]
 #[should_panic(expected = "Move '' is not a number")]
 fn test_parse_empty() {
@@ -104,3 +163,16 @@ fn test_parse_success() {
     let board_move = parse(&board_dimension, num.to_string().as_str()).unwrap();
     assert_eq!(board_move.value, num);
 }
+
+#[test]
+fn test_parse_column() {
+    let board_dimension = parse_board_dimension("4").unwrap();
+    assert_eq!(parse_column(&board_dimension, " 4 ").unwrap(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Column can not be greater than 3")]
+fn test_parse_column_greater_than_max() {
+    let board_dimension = parse_board_dimension("3").unwrap();
+    parse_column(&board_dimension, "5").unwrap();
+}
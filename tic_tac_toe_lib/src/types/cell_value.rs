@@ -1,7 +1,7 @@
 use crate::types::player_mark::PlayerMark;
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CellValue(Option<PlayerMark>);
 
 impl fmt::Display for CellValue {
@@ -0,0 +1,68 @@
+// NOTE 16: Source of time for time controls. Games use the system clock,
+//          tests use a manual clock and advance it deterministically.
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Shared between the timer and the caller, a game with a clock can still move between threads
+pub trait Clock: fmt::Debug + Send + Sync {
+    // Time passed since some fixed point, only differences are used
+    fn now(&self) -> Duration;
+}
+
+#[derive(Debug)]
+pub struct SystemClock {
+    started: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock {
+            started: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now: Mutex<Duration>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[test]
+fn test_manual_clock_advance() {
+    let clock = ManualClock::new();
+    assert_eq!(clock.now(), Duration::ZERO);
+
+    clock.advance(Duration::from_millis(1500));
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(clock.now(), Duration::from_millis(3500));
+}
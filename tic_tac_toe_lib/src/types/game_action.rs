@@ -0,0 +1,58 @@
+// NOTE 15: Actions that are not board moves. They are recorded in the move history
+//          next to the board moves as "<mark> <action>", for example "X offers draw".
+
+use crate::types::player_mark::{parse as parse_player_mark, PlayerMark};
+use enum_iterator::IntoEnumIterator;
+use std::fmt;
+
+#[derive(Debug, IntoEnumIterator, PartialEq, Clone, Copy)]
+pub enum GameAction {
+    Resign,
+    OfferDraw,
+    AcceptDraw,
+    DeclineDraw,
+    Swap,
+}
+
+impl fmt::Display for GameAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            GameAction::Resign => "resigns",
+            GameAction::OfferDraw => "offers draw",
+            GameAction::AcceptDraw => "accepts draw",
+            GameAction::DeclineDraw => "declines draw",
+            GameAction::Swap => "swaps",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+pub fn get_history_entry(player_mark: &PlayerMark, action: GameAction) -> String {
+    format!("{} {}", player_mark, action)
+}
+
+// None for board moves
+pub fn parse_history_entry(s: &str) -> Option<(PlayerMark, GameAction)> {
+    let (mark_str, action_str) = s.trim().split_once(' ')?;
+    let player_mark = parse_player_mark(mark_str).ok()?;
+    let action = GameAction::into_enum_iter().find(|a| a.to_string() == action_str.trim())?;
+    Some((player_mark, action))
+}
+
+#[test]
+fn test_history_entry_round_trip() {
+    for action in GameAction::into_enum_iter() {
+        let entry = get_history_entry(&PlayerMark::O, action);
+        assert_eq!(parse_history_entry(&entry), Some((PlayerMark::O, action)));
+    }
+    assert_eq!(
+        get_history_entry(&PlayerMark::X, GameAction::Resign),
+        "X resigns"
+    );
+}
+
+#[test]
+fn test_parse_history_entry_board_move() {
+    assert_eq!(parse_history_entry("5"), None);
+    assert_eq!(parse_history_entry("X plays"), None);
+}
@@ -0,0 +1,50 @@
+// Move that names the mark to place after the cell or column, for example '5O'.
+// Used when a player may place either mark.
+
+use crate::types::errors::TicTacToeError;
+use crate::types::player_mark::{get_two_player_marks, parse as parse_player_mark, PlayerMark};
+use anyhow::bail;
+
+// Cell or column part of the move, and the mark to place there, X or O
+pub fn split_mark(s: &str) -> Result<(&str, PlayerMark), TicTacToeError> {
+    let str = s.trim();
+    let (position, mark) = str.split_at(str.trim_end_matches(char::is_alphabetic).len());
+
+    if position.trim().is_empty() || mark.is_empty() {
+        bail!(
+            "Move '{}' must end with the mark to place, for example 5O",
+            s
+        );
+    }
+    match parse_player_mark(mark) {
+        Ok(pm) if get_two_player_marks().contains(&pm) => Ok((position, pm)),
+        _ => bail!(
+            "Move '{}' must end with the mark to place, for example 5O",
+            s
+        ),
+    }
+}
+
+#[test]
+fn test_split_mark() {
+    assert_eq!(split_mark(" 5O ").unwrap(), ("5", PlayerMark::O));
+    assert_eq!(split_mark("12 x").unwrap(), ("12 ", PlayerMark::X));
+}
+
+#[test]
+#[should_panic(expected = "Move '5' must end with the mark to place, for example 5O")]
+fn test_split_mark_missing() {
+    let _m = split_mark("5").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Move '5Z' must end with the mark to place")]
+fn test_split_mark_unknown() {
+    let _m = split_mark("5Z").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Move '5T' must end with the mark to place")]
+fn test_split_mark_extra_player_mark() {
+    let _m = split_mark("5T").unwrap();
+}
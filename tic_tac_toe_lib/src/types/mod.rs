@@ -1,6 +1,29 @@
+pub mod blocked_cells;
 pub mod board;
+pub mod board_count;
 pub mod board_dimension;
 pub mod board_move;
+#[cfg(any(test, feature = "bench"))]
 mod cell_value;
+pub mod clock;
 pub mod errors;
+pub mod game_action;
+pub mod marked_move;
+pub mod notakto_move;
+pub mod outcome;
+pub mod piece_count;
+pub mod player_count;
 pub mod player_mark;
+pub mod qubic_move;
+pub mod role;
+pub mod slide_move;
+pub mod symmetry;
+pub mod time_control;
+pub mod toggle;
+pub mod turn_order;
+pub mod ultimate_move;
+pub mod variant;
+#[cfg(any(test, feature = "bench"))]
+pub mod vec_board;
+pub mod win_length;
+pub mod win_rule;
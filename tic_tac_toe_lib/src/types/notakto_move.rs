@@ -0,0 +1,88 @@
+// Move of Notakto: number of the board, then number of the cell on it,
+// for example '2-5'. It can be created only through parse.
+
+use crate::types::board_count::BoardCount;
+use crate::types::board_dimension::BoardDimension;
+use crate::types::board_move::{parse as parse_board_move, BoardMove};
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+use std::fmt;
+
+pub struct NotaktoMove {
+    // Counted from 1
+    pub board_number: u8,
+    pub cell: BoardMove,
+}
+
+impl fmt::Display for NotaktoMove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.board_number, self.cell.get_value())
+    }
+}
+
+pub fn parse(
+    board_dimension: &BoardDimension,
+    board_count: &BoardCount,
+    s: &str,
+) -> Result<NotaktoMove, TicTacToeError> {
+    let (board_str, cell_str) = match s.trim().split_once('-') {
+        Some(parts) => parts,
+        None => bail!("Move '{}' must be given as board-cell, for example 2-5", s),
+    };
+
+    let board_number = match board_str.trim().parse::<u8>() {
+        Ok(val) => val,
+        Err(_) => bail!("Board '{}' is not a number", board_str),
+    };
+    if board_number < 1 {
+        bail!("Board can not be less than 1");
+    }
+    if board_number > board_count.get_value() {
+        bail!("Board can not be greater than {}", board_count.get_value());
+    }
+
+    Ok(NotaktoMove {
+        board_number,
+        cell: parse_board_move(board_dimension, cell_str)?,
+    })
+}
+
+#[cfg(test)]
+use crate::types::board_count::parse as parse_board_count;
+#[cfg(test)]
+use crate::types::board_dimension::parse as parse_board_dimension;
+
+#[test]
+fn test_parse() {
+    let notakto_move = parse(
+        &parse_board_dimension("3").unwrap(),
+        &parse_board_count("2").unwrap(),
+        " 2 - 5 ",
+    )
+    .unwrap();
+    assert_eq!(notakto_move.board_number, 2);
+    assert_eq!(notakto_move.cell.get_indices(), (1, 1));
+    assert_eq!(notakto_move.to_string(), "2-5");
+}
+
+#[test]
+#[should_panic(expected = "Board can not be greater than 2")]
+fn test_parse_board_greater_than_count() {
+    let _m = parse(
+        &parse_board_dimension("3").unwrap(),
+        &parse_board_count("2").unwrap(),
+        "3-1",
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Move '5' must be given as board-cell, for example 2-5")]
+fn test_parse_without_board() {
+    let _m = parse(
+        &parse_board_dimension("3").unwrap(),
+        &parse_board_count("1").unwrap(),
+        "5",
+    )
+    .unwrap();
+}
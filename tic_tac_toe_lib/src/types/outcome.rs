@@ -0,0 +1,103 @@
+// NOTE 14: Typed result of a game. Every finished game has a winner or is a draw,
+//          and carries the reason why it ended. The winner is a player mark unless
+//          the players have roles, like in Order and Chaos.
+
+use crate::types::player_mark::PlayerMark;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome<W = PlayerMark> {
+    InProgress,
+    Win {
+        winner: W,
+        reason: WinReason,
+        // Cell numbers of the completed line, empty when the game did not end on the board
+        line: Vec<u8>,
+    },
+    Draw {
+        reason: DrawReason,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WinReason {
+    Line,
+    // The opponent completed a line and lost by the misere rule
+    Misere,
+    // The opponent completed a line on the last board still alive in Notakto
+    LastBoard,
+    // The board filled without a line, Chaos wins in Order and Chaos
+    BoardFull,
+    Resignation,
+    Timeout,
+    // All other players completed a line or resigned with the elimination rule
+    Elimination,
+    // The opponent can not slide any piece in the Sliding variant
+    Blocked,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrawReason {
+    BoardFull,
+    DeadPosition,
+    Agreement,
+    // The same position came up for the third time in the Sliding variant
+    Repetition,
+}
+
+impl fmt::Display for WinReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl fmt::Display for DrawReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl<W> Outcome<W> {
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, Outcome::InProgress)
+    }
+
+    pub fn get_winner(&self) -> Option<&W> {
+        match self {
+            Outcome::Win { winner, .. } => Some(winner),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_is_finished() {
+    assert!(!Outcome::<PlayerMark>::InProgress.is_finished());
+    assert!(Outcome::<PlayerMark>::Draw {
+        reason: DrawReason::BoardFull
+    }
+    .is_finished());
+    assert!(Outcome::Win {
+        winner: PlayerMark::X,
+        reason: WinReason::Resignation,
+        line: vec![],
+    }
+    .is_finished());
+}
+
+#[test]
+fn test_get_winner() {
+    let outcome = Outcome::Win {
+        winner: PlayerMark::O,
+        reason: WinReason::Line,
+        line: vec![1, 5, 9],
+    };
+    assert_eq!(outcome.get_winner(), Some(&PlayerMark::O));
+    assert_eq!(
+        Outcome::<PlayerMark>::Draw {
+            reason: DrawReason::Agreement
+        }
+        .get_winner(),
+        None
+    );
+}
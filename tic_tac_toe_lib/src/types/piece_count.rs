@@ -0,0 +1,67 @@
+// Number of pieces every player has in the Sliding variant.
+// u8 type with constraints. It can be created only through parse.
+
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PieceCount(u8);
+
+const MIN_VALUE: u8 = 3;
+const MAX_VALUE: u8 = 8;
+const DEFAULT: &str = "Line Length";
+
+impl PieceCount {
+    pub fn get_value(&self) -> u8 {
+        self.0
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    let mut vec_str = vec![get_default_as_str()];
+    vec_str.extend((MIN_VALUE..=MAX_VALUE).map(|i| i.to_string()));
+    vec_str
+}
+
+pub fn get_default() -> Option<PieceCount> {
+    None
+}
+
+pub fn get_default_as_str() -> String {
+    String::from(DEFAULT)
+}
+
+// None when every player has as many pieces as a line is long
+pub fn parse(s: &str) -> Result<Option<PieceCount>, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() || str.to_lowercase() == DEFAULT.to_lowercase() {
+        return Ok(get_default());
+    }
+
+    let val = match str.parse::<u8>() {
+        Ok(val) => val,
+        Err(_) => bail!("Piece Count '{}' is not a number", s),
+    };
+    if val < MIN_VALUE {
+        bail!("Piece Count can not be less than {}", MIN_VALUE);
+    }
+    if val > MAX_VALUE {
+        bail!("Piece Count can not be greater than {}", MAX_VALUE);
+    }
+
+    Ok(Some(PieceCount(val)))
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse(" ").unwrap(), None);
+    assert_eq!(parse("line length").unwrap(), None);
+    assert_eq!(parse("4").unwrap(), Some(PieceCount(4)));
+}
+
+#[test]
+#[should_panic(expected = "Piece Count can not be less than 3")]
+fn test_parse_less_than_min() {
+    let _pc = parse("2").unwrap();
+}
@@ -0,0 +1,66 @@
+// Number of players, games of more than two players use the marks Δ and □ too.
+// u8 type with constraints. It can be created only through parse.
+
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PlayerCount(u8);
+
+const MIN_VALUE: u8 = 2;
+const MAX_VALUE: u8 = 4;
+const DEFAULT_VALUE: u8 = 2;
+
+impl PlayerCount {
+    pub fn get_value(&self) -> u8 {
+        self.0
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    (MIN_VALUE..=MAX_VALUE).map(|i| i.to_string()).collect()
+}
+
+pub fn get_default() -> PlayerCount {
+    PlayerCount(DEFAULT_VALUE)
+}
+
+pub fn parse(s: &str) -> Result<PlayerCount, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() {
+        return Ok(get_default());
+    }
+
+    let val = match str.parse::<u8>() {
+        Ok(val) => val,
+        Err(_) => bail!("Player Count '{}' is not a number", s),
+    };
+    if val < MIN_VALUE {
+        bail!("Player Count can not be less than {}", MIN_VALUE);
+    }
+    if val > MAX_VALUE {
+        bail!("Player Count can not be greater than {}", MAX_VALUE);
+    }
+
+    Ok(PlayerCount(val))
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("").unwrap(), get_default());
+    assert_eq!(parse(" 3 ").unwrap().get_value(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Player Count can not be greater than 4")]
+fn test_parse_greater_than_max() {
+    let _pc = parse("5").unwrap();
+}
+
+#[test]
+fn test_business_rules() {
+    assert_eq!(MIN_VALUE, 2);
+    assert_eq!(MAX_VALUE, 4);
+    assert_eq!(DEFAULT_VALUE, 2);
+}
@@ -5,17 +5,49 @@ use anyhow::bail;
 use enum_iterator::IntoEnumIterator;
 use std::fmt;
 
-#[derive(Debug, IntoEnumIterator, PartialEq, Clone)]
+#[derive(Debug, IntoEnumIterator, PartialEq, Eq, Hash, Clone)]
 pub enum PlayerMark {
     X,
     O,
+    // Marks of the third and fourth player
+    Triangle,
+    Square,
 }
 
 const DEFAULT_FIRST_PLAYER_MARK: PlayerMark = PlayerMark::X;
 
 impl fmt::Display for PlayerMark {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            PlayerMark::Triangle => write!(f, "Δ"),
+            PlayerMark::Square => write!(f, "□"),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl PlayerMark {
+    // Position in the enum, used to index per-mark data like bitboards
+    pub fn get_index(&self) -> usize {
+        self.clone() as usize
+    }
+
+    // The other player of a two player game, those are always played by X and O
+    pub fn get_opponent(&self) -> PlayerMark {
+        match self {
+            PlayerMark::X => PlayerMark::O,
+            PlayerMark::O => PlayerMark::X,
+            _ => unreachable!("Mark {} plays only with more than two players", self),
+        }
+    }
+
+    // Letter that can be typed instead of the mark
+    fn get_alias(&self) -> Option<&str> {
+        match self {
+            PlayerMark::Triangle => Some("T"),
+            PlayerMark::Square => Some("S"),
+            _ => None,
+        }
     }
 }
 
@@ -25,6 +57,11 @@ pub fn get_all_as_vec_str() -> Vec<String> {
         .collect()
 }
 
+// Marks of two player games, either of them may be placed in Wild and Order and Chaos
+pub fn get_two_player_marks() -> Vec<PlayerMark> {
+    vec![PlayerMark::X, PlayerMark::O]
+}
+
 pub fn get_default() -> PlayerMark {
     DEFAULT_FIRST_PLAYER_MARK
 }
@@ -32,12 +69,17 @@ pub fn get_default() -> PlayerMark {
 pub fn parse(s: &str) -> Result<PlayerMark, TicTacToeError> {
     let str = s.trim();
 
+    #[allow(clippy::len_zero)]
     if str.len() == 0 {
         return Ok(DEFAULT_FIRST_PLAYER_MARK);
     }
 
     for pm in PlayerMark::into_enum_iter() {
-        if str.to_lowercase() == pm.to_string().to_lowercase() {
+        if str.to_lowercase() == pm.to_string().to_lowercase()
+            || pm
+                .get_alias()
+                .is_some_and(|alias| str.to_lowercase() == alias.to_lowercase())
+        {
             return Ok(pm);
         }
     }
@@ -62,6 +104,15 @@ fn test_parse_o_with_spaces_return_o() {
     assert_eq!(parse(" O ").unwrap(), PlayerMark::O);
 }
 
+#[test]
+fn test_parse_extra_marks() {
+    assert_eq!(parse("Δ").unwrap(), PlayerMark::Triangle);
+    assert_eq!(parse(" t ").unwrap(), PlayerMark::Triangle);
+    assert_eq!(parse("□").unwrap(), PlayerMark::Square);
+    assert_eq!(parse("S").unwrap(), PlayerMark::Square);
+    assert_eq!(get_all_as_vec_str(), vec!["X", "O", "Δ", "□"]);
+}
+
 #[test]
 #[should_panic(expected = "Player Mark ' abc ' is not recognized")]
 fn test_parse_wrong_string() {
@@ -0,0 +1,90 @@
+// Move of 3D tic-tac-toe: layer, row and column, each counted from 1, for example
+// '2-1-3'. It can be created only through parse.
+
+use crate::types::board_dimension::BoardDimension;
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+use std::fmt;
+
+pub struct QubicMove {
+    layer_idx: u8,
+    row_idx: u8,
+    col_idx: u8,
+}
+
+impl QubicMove {
+    pub fn get_indices(&self) -> (u8, u8, u8) {
+        (self.layer_idx, self.row_idx, self.col_idx)
+    }
+}
+
+impl fmt::Display for QubicMove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}",
+            self.layer_idx + 1,
+            self.row_idx + 1,
+            self.col_idx + 1
+        )
+    }
+}
+
+pub fn parse(board_dimension: &BoardDimension, s: &str) -> Result<QubicMove, TicTacToeError> {
+    let parts: Vec<&str> = s.trim().split('-').collect();
+    if parts.len() != 3 {
+        bail!(
+            "Move '{}' must be given as layer-row-column, for example 2-1-3",
+            s
+        );
+    }
+
+    let mut indices: Vec<u8> = vec![];
+    for part in parts {
+        let val = match part.trim().parse::<u8>() {
+            Ok(val) => val,
+            Err(_) => bail!("Move '{}' is not a number", part),
+        };
+        if val < 1 {
+            bail!("Move can not be less than 1");
+        }
+        if val > board_dimension.get_width() {
+            bail!(
+                "Move can not be greater than {}",
+                board_dimension.get_width()
+            );
+        }
+        indices.push(val - 1);
+    }
+
+    Ok(QubicMove {
+        layer_idx: indices[0],
+        row_idx: indices[1],
+        col_idx: indices[2],
+    })
+}
+
+#[test]
+fn test_parse() {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+
+    let qubic_move = parse(&parse_board_dimension("4").unwrap(), " 4-1 - 2").unwrap();
+    assert_eq!(qubic_move.get_indices(), (3, 0, 1));
+    assert_eq!(qubic_move.to_string(), "4-1-2");
+}
+
+#[test]
+#[should_panic(expected = "Move can not be greater than 3")]
+fn test_parse_out_of_cube() {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+
+    let _m = parse(&parse_board_dimension("3").unwrap(), "1-4-1").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Move '5' must be given as layer-row-column, for example 2-1-3")]
+fn test_parse_cell_number() {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+
+    let _m = parse(&parse_board_dimension("3").unwrap(), "5").unwrap();
+}
@@ -0,0 +1,27 @@
+// Role of a player in games where both players place both marks, like Order and Chaos.
+// Roles take turns like marks do in the other games.
+
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Role {
+    // Wants a line of one mark
+    Order,
+    // Wants the board full without one
+    Chaos,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Role {
+    pub fn get_opponent(&self) -> Role {
+        match self {
+            Role::Order => Role::Chaos,
+            Role::Chaos => Role::Order,
+        }
+    }
+}
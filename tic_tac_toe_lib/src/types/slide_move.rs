@@ -0,0 +1,72 @@
+// Move of the Sliding variant once all pieces are placed: the cell a piece is taken
+// from, then the cell it slides to, for example '1-5'. It can be created only through parse.
+
+use crate::types::board_dimension::BoardDimension;
+use crate::types::board_move::{parse as parse_board_move, BoardMove};
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+use std::fmt;
+
+pub struct SlideMove {
+    pub from: BoardMove,
+    pub to: BoardMove,
+}
+
+impl SlideMove {
+    // Every cell around the one the piece is taken from, diagonals included
+    pub fn is_adjacent(&self) -> bool {
+        let (from_row, from_col) = self.from.get_indices();
+        let (to_row, to_col) = self.to.get_indices();
+        from_row.abs_diff(to_row) <= 1 && from_col.abs_diff(to_col) <= 1
+    }
+}
+
+impl fmt::Display for SlideMove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.from.get_value(), self.to.get_value())
+    }
+}
+
+pub fn parse(board_dimension: &BoardDimension, s: &str) -> Result<SlideMove, TicTacToeError> {
+    let (from_str, to_str) = match s.trim().split_once('-') {
+        Some(parts) => parts,
+        None => bail!("Move '{}' must be given as from-to, for example 1-5", s),
+    };
+
+    let slide_move = SlideMove {
+        from: parse_board_move(board_dimension, from_str)?,
+        to: parse_board_move(board_dimension, to_str)?,
+    };
+    if slide_move.from.get_value() == slide_move.to.get_value() {
+        bail!("Move '{}' must slide the piece to another cell", s);
+    }
+
+    Ok(slide_move)
+}
+
+#[cfg(test)]
+use crate::types::board_dimension::parse as parse_board_dimension;
+
+#[test]
+fn test_parse() {
+    let slide_move = parse(&parse_board_dimension("3").unwrap(), " 1 - 5 ").unwrap();
+    assert_eq!(slide_move.from.get_indices(), (0, 0));
+    assert_eq!(slide_move.to.get_indices(), (1, 1));
+    assert_eq!(slide_move.to_string(), "1-5");
+    assert!(slide_move.is_adjacent());
+
+    let slide_move = parse(&parse_board_dimension("3").unwrap(), "1-3").unwrap();
+    assert!(!slide_move.is_adjacent());
+}
+
+#[test]
+#[should_panic(expected = "Move '5' must be given as from-to, for example 1-5")]
+fn test_parse_without_separator() {
+    let _m = parse(&parse_board_dimension("3").unwrap(), "5").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Move '5-5' must slide the piece to another cell")]
+fn test_parse_same_cell() {
+    let _m = parse(&parse_board_dimension("3").unwrap(), "5-5").unwrap();
+}
@@ -0,0 +1,113 @@
+// NOTE 13: The 8 symmetries of a square (dihedral group). Positions that map onto each
+//          other are equivalent, so search and tablebase code can store only one of them.
+//          A rectangle keeps only the 4 symmetries that do not swap rows and columns.
+
+use crate::types::board_dimension::BoardDimension;
+use enum_iterator::IntoEnumIterator;
+
+#[derive(Debug, IntoEnumIterator, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Symmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    FlipDiagonal,
+    FlipAntiDiagonal,
+}
+
+impl Symmetry {
+    // Symmetries that map a board of the given dimension onto itself
+    pub fn get_all(board_dimension: &BoardDimension) -> Vec<Symmetry> {
+        Symmetry::into_enum_iter()
+            .filter(|symmetry| board_dimension.is_square() || !symmetry.swaps_sides())
+            .collect()
+    }
+
+    // Cell the given cell moves to, rotations by 90 degrees and diagonal flips
+    // are valid only on square boards
+    pub fn apply(
+        &self,
+        board_dimension: &BoardDimension,
+        (row_idx, col_idx): (usize, usize),
+    ) -> (usize, usize) {
+        let last_row = board_dimension.get_height() as usize - 1;
+        let last_col = board_dimension.get_width() as usize - 1;
+        match self {
+            Symmetry::Identity => (row_idx, col_idx),
+            Symmetry::Rotate90 => (col_idx, last_row - row_idx),
+            Symmetry::Rotate180 => (last_row - row_idx, last_col - col_idx),
+            Symmetry::Rotate270 => (last_col - col_idx, row_idx),
+            Symmetry::FlipHorizontal => (row_idx, last_col - col_idx),
+            Symmetry::FlipVertical => (last_row - row_idx, col_idx),
+            Symmetry::FlipDiagonal => (col_idx, row_idx),
+            Symmetry::FlipAntiDiagonal => (last_col - col_idx, last_row - row_idx),
+        }
+    }
+
+    pub fn get_inverse(&self) -> Symmetry {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            other => *other,
+        }
+    }
+
+    fn swaps_sides(&self) -> bool {
+        matches!(
+            self,
+            Symmetry::Rotate90
+                | Symmetry::Rotate270
+                | Symmetry::FlipDiagonal
+                | Symmetry::FlipAntiDiagonal
+        )
+    }
+}
+
+#[cfg(test)]
+use crate::types::board_dimension::parse as parse_board_dimension;
+
+#[test]
+fn test_apply_rotate90() {
+    // 1 2 3      7 4 1
+    // 4 5 6  ->  8 5 2
+    // 7 8 9      9 6 3
+    let board_dimension = parse_board_dimension("3").unwrap();
+    assert_eq!(Symmetry::Rotate90.apply(&board_dimension, (0, 0)), (0, 2));
+    assert_eq!(Symmetry::Rotate90.apply(&board_dimension, (0, 1)), (1, 2));
+    assert_eq!(Symmetry::Rotate90.apply(&board_dimension, (2, 0)), (0, 0));
+    assert_eq!(Symmetry::Rotate90.apply(&board_dimension, (1, 1)), (1, 1));
+}
+
+#[test]
+fn test_inverse_restores_cell() {
+    let board_dimension = parse_board_dimension("4").unwrap();
+    for symmetry in Symmetry::into_enum_iter() {
+        for row_idx in 0..4 {
+            for col_idx in 0..4 {
+                let moved = symmetry.apply(&board_dimension, (row_idx, col_idx));
+                assert_eq!(
+                    symmetry.get_inverse().apply(&board_dimension, moved),
+                    (row_idx, col_idx)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_get_all_rectangular() {
+    use crate::types::board_dimension::parse_sides;
+
+    let board_dimension =
+        parse_sides(&parse_board_dimension("3").unwrap(), Some("4"), None).unwrap();
+    let symmetries = Symmetry::get_all(&board_dimension);
+    assert_eq!(symmetries.len(), 4);
+    // 4 wide, 3 high: the corners swap by rotating half a turn
+    assert_eq!(Symmetry::Rotate180.apply(&board_dimension, (0, 0)), (2, 3));
+    assert_eq!(
+        Symmetry::get_all(&parse_board_dimension("4").unwrap()).len(),
+        8
+    );
+}
@@ -0,0 +1,126 @@
+// Chess clock settings, in whole seconds. It can be created only through parse.
+
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TimeControl {
+    // Time for the whole game, the increment is added after every move
+    Total {
+        total: Duration,
+        increment: Duration,
+    },
+    // Every move has to be played within the same time
+    PerMove(Duration),
+}
+
+const OFF: &str = "Off";
+
+impl fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeControl::Total { total, increment } if increment.is_zero() => {
+                write!(f, "{}", total.as_secs())
+            }
+            TimeControl::Total { total, increment } => {
+                write!(f, "{}+{}", total.as_secs(), increment.as_secs())
+            }
+            TimeControl::PerMove(per_move) => write!(f, "{}/move", per_move.as_secs()),
+        }
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    vec![
+        String::from(OFF),
+        String::from("<seconds>"),
+        String::from("<seconds>+<increment>"),
+        String::from("<seconds>/move"),
+    ]
+}
+
+pub fn get_default() -> Option<TimeControl> {
+    None
+}
+
+pub fn get_default_as_str() -> String {
+    match get_default() {
+        None => String::from(OFF),
+        Some(time_control) => time_control.to_string(),
+    }
+}
+
+// None when time is not controlled
+pub fn parse(s: &str) -> Result<Option<TimeControl>, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() || str.to_lowercase() == OFF.to_lowercase() {
+        return Ok(None);
+    }
+
+    let time_control = if let Some(per_move) = str.strip_suffix("/move") {
+        TimeControl::PerMove(parse_seconds(s, per_move)?)
+    } else if let Some((total, increment)) = str.split_once('+') {
+        TimeControl::Total {
+            total: parse_seconds(s, total)?,
+            increment: parse_seconds(s, increment)?,
+        }
+    } else {
+        TimeControl::Total {
+            total: parse_seconds(s, str)?,
+            increment: Duration::ZERO,
+        }
+    };
+
+    let (TimeControl::Total { total, .. } | TimeControl::PerMove(total)) = &time_control;
+    if total.is_zero() {
+        bail!("Time Control '{}' must give some time to play", s);
+    }
+
+    Ok(Some(time_control))
+}
+
+fn parse_seconds(s: &str, seconds: &str) -> Result<Duration, TicTacToeError> {
+    match seconds.trim().parse::<u64>() {
+        Ok(seconds) => Ok(Duration::from_secs(seconds)),
+        Err(_) => bail!(
+            "Time Control '{}' is not recognized, use {}",
+            s,
+            get_all_as_vec_str().join(", ")
+        ),
+    }
+}
+
+#[test]
+fn test_parse_off() {
+    assert_eq!(parse("").unwrap(), None);
+    assert_eq!(parse(" off ").unwrap(), None);
+}
+
+#[test]
+fn test_parse_and_display() {
+    for s in ["300", "300+5", "10/move"] {
+        assert_eq!(parse(s).unwrap().unwrap().to_string(), s);
+    }
+    assert_eq!(
+        parse(" 60 + 2 ").unwrap(),
+        Some(TimeControl::Total {
+            total: Duration::from_secs(60),
+            increment: Duration::from_secs(2),
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Time Control '5 min' is not recognized")]
+fn test_parse_wrong_string() {
+    let _tc = parse("5 min").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Time Control '0/move' must give some time to play")]
+fn test_parse_zero() {
+    let _tc = parse("0/move").unwrap();
+}
@@ -0,0 +1,73 @@
+// On/Off value for optional rules. It can be created only through parse.
+
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+use enum_iterator::IntoEnumIterator;
+use std::fmt;
+
+#[derive(Debug, IntoEnumIterator, PartialEq, Clone)]
+pub enum Toggle {
+    Off,
+    On,
+}
+
+const DEFAULT_TOGGLE: Toggle = Toggle::Off;
+
+impl fmt::Display for Toggle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Toggle {
+    pub fn is_on(&self) -> bool {
+        *self == Toggle::On
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    Toggle::into_enum_iter().map(|t| t.to_string()).collect()
+}
+
+pub fn get_default() -> Toggle {
+    DEFAULT_TOGGLE
+}
+
+pub fn parse(s: &str) -> Result<Toggle, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() {
+        return Ok(DEFAULT_TOGGLE);
+    }
+
+    for t in Toggle::into_enum_iter() {
+        if str.to_lowercase() == t.to_string().to_lowercase() {
+            return Ok(t);
+        }
+    }
+
+    bail!("Value '{}' is not recognized, use On or Off", s);
+}
+
+#[test]
+fn test_parse_empty_return_default() {
+    assert_eq!(parse("").unwrap(), DEFAULT_TOGGLE);
+    assert_eq!(parse(" ").unwrap(), DEFAULT_TOGGLE);
+}
+
+#[test]
+fn test_parse_success() {
+    assert_eq!(parse(" on ").unwrap(), Toggle::On);
+    assert_eq!(parse("OFF").unwrap(), Toggle::Off);
+}
+
+#[test]
+#[should_panic(expected = "Value 'yes' is not recognized, use On or Off")]
+fn test_parse_wrong_string() {
+    let _t = parse("yes").unwrap();
+}
+
+#[test]
+fn test_business_rules() {
+    assert_eq!(DEFAULT_TOGGLE, Toggle::Off);
+}
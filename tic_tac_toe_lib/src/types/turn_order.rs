@@ -0,0 +1,115 @@
+// Marks of the players in the order they move, for example 'XΔO'. The players of a game
+// of N players use the first N marks of X, O, Δ, □. It can be created only through parse.
+
+use crate::types::errors::TicTacToeError;
+use crate::types::player_count::{get_default as get_default_player_count, PlayerCount};
+use crate::types::player_mark::{parse as parse_player_mark, PlayerMark};
+use anyhow::bail;
+use enum_iterator::IntoEnumIterator;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TurnOrder(Vec<PlayerMark>);
+
+impl TurnOrder {
+    pub fn get_players(&self) -> &[PlayerMark] {
+        &self.0
+    }
+
+    // Player moving after the given one
+    pub fn get_next(&self, player_mark: &PlayerMark) -> PlayerMark {
+        let idx = self.0.iter().position(|pm| pm == player_mark).unwrap();
+        self.0[(idx + 1) % self.0.len()].clone()
+    }
+}
+
+pub fn get_allowed_values_as_str() -> String {
+    String::from("<marks in order, for example XOΔ or XOT>")
+}
+
+pub fn get_default() -> TurnOrder {
+    get_default_for(&get_default_player_count())
+}
+
+pub fn get_default_for(player_count: &PlayerCount) -> TurnOrder {
+    TurnOrder(
+        PlayerMark::into_enum_iter()
+            .take(player_count.get_value() as usize)
+            .collect(),
+    )
+}
+
+// Without a player count the number of marks given is taken
+pub fn parse(s: &str, player_count: Option<&PlayerCount>) -> Result<TurnOrder, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() {
+        return Ok(get_default_for(
+            player_count.unwrap_or(&get_default_player_count()),
+        ));
+    }
+
+    let mut marks: Vec<PlayerMark> = vec![];
+    for c in str.chars().filter(|c| !c.is_whitespace()) {
+        let pm = parse_player_mark(&c.to_string())?;
+        if marks.contains(&pm) {
+            bail!("Turn Order '{}' names player {} more than once", s, pm);
+        }
+        marks.push(pm);
+    }
+
+    let count = player_count.map_or(marks.len(), |pc| pc.get_value() as usize);
+    if marks.len() != count {
+        bail!("Turn Order '{}' must name {} players", s, count);
+    }
+    let expected = get_default_for(&crate::types::player_count::parse(&count.to_string())?);
+    if expected.0.iter().any(|pm| !marks.contains(pm)) {
+        let names: Vec<String> = expected.0.iter().map(|pm| pm.to_string()).collect();
+        bail!("Turn Order '{}' must use the marks {}", s, names.join(", "));
+    }
+
+    Ok(TurnOrder(marks))
+}
+
+#[test]
+fn test_parse() {
+    use crate::types::player_count::parse as parse_player_count;
+
+    assert_eq!(parse("", None).unwrap(), get_default());
+    assert_eq!(
+        parse("", Some(&parse_player_count("3").unwrap()))
+            .unwrap()
+            .get_players(),
+        [PlayerMark::X, PlayerMark::O, PlayerMark::Triangle]
+    );
+    assert_eq!(
+        parse(" xTo ", None).unwrap().get_players(),
+        [PlayerMark::X, PlayerMark::Triangle, PlayerMark::O]
+    );
+}
+
+#[test]
+fn test_get_next() {
+    let turn_order = parse("XΔO", None).unwrap();
+    assert_eq!(turn_order.get_next(&PlayerMark::X), PlayerMark::Triangle);
+    assert_eq!(turn_order.get_next(&PlayerMark::O), PlayerMark::X);
+}
+
+#[test]
+#[should_panic(expected = "Turn Order 'XO' must name 3 players")]
+fn test_parse_wrong_count() {
+    use crate::types::player_count::parse as parse_player_count;
+
+    let _to = parse("XO", Some(&parse_player_count("3").unwrap())).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Turn Order 'XOS' must use the marks X, O, Δ")]
+fn test_parse_skipped_mark() {
+    let _to = parse("XOS", None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Turn Order 'XOX' names player X more than once")]
+fn test_parse_duplicate() {
+    let _to = parse("XOX", None).unwrap();
+}
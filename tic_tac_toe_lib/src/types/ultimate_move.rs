@@ -0,0 +1,49 @@
+// Move of Ultimate tic-tac-toe: number of the small board, then number of the cell
+// in it, for example '5-3'. It can be created only through parse.
+
+use crate::types::board_dimension::BoardDimension;
+use crate::types::board_move::{parse as parse_board_move, BoardMove};
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+use std::fmt;
+
+pub struct UltimateMove {
+    pub board: BoardMove,
+    pub cell: BoardMove,
+}
+
+impl fmt::Display for UltimateMove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.board.get_value(), self.cell.get_value())
+    }
+}
+
+pub fn parse(board_dimension: &BoardDimension, s: &str) -> Result<UltimateMove, TicTacToeError> {
+    let (board_str, cell_str) = match s.trim().split_once('-') {
+        Some(parts) => parts,
+        None => bail!("Move '{}' must be given as board-cell, for example 5-3", s),
+    };
+
+    Ok(UltimateMove {
+        board: parse_board_move(board_dimension, board_str)?,
+        cell: parse_board_move(board_dimension, cell_str)?,
+    })
+}
+
+#[test]
+fn test_parse() {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+
+    let ultimate_move = parse(&parse_board_dimension("3").unwrap(), " 7 - 2 ").unwrap();
+    assert_eq!(ultimate_move.board.get_value(), 7);
+    assert_eq!(ultimate_move.cell.get_indices(), (0, 1));
+    assert_eq!(ultimate_move.to_string(), "7-2");
+}
+
+#[test]
+#[should_panic(expected = "Move '52' must be given as board-cell, for example 5-3")]
+fn test_parse_without_separator() {
+    use crate::types::board_dimension::parse as parse_board_dimension;
+
+    let _m = parse(&parse_board_dimension("3").unwrap(), "52").unwrap();
+}
@@ -0,0 +1,74 @@
+// Kind of game the settings are for. Variants that are not played on a single
+// board have their own game type. It can be created only through parse.
+
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+use enum_iterator::IntoEnumIterator;
+use std::fmt;
+
+#[derive(Debug, IntoEnumIterator, PartialEq, Clone)]
+pub enum Variant {
+    Classic,
+    // Board of boards, played with UltimateGame
+    Ultimate,
+    // Cube of 3x3x3 or 4x4x4 cells, played with QubicGame
+    Qubic,
+    // Either player may place either mark, played with Game
+    Wild,
+    // Both players place X on one or more boards, played with NotaktoGame
+    Notakto,
+    // Order wants five in a row on 6x6 and Chaos does not, played with OrderChaosGame
+    OrderChaos,
+    // Pieces slide to other cells once all are placed, played with SlidingGame
+    Sliding,
+}
+
+const DEFAULT_VARIANT: Variant = Variant::Classic;
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    Variant::into_enum_iter().map(|v| v.to_string()).collect()
+}
+
+pub fn get_default() -> Variant {
+    DEFAULT_VARIANT
+}
+
+pub fn parse(s: &str) -> Result<Variant, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() {
+        return Ok(DEFAULT_VARIANT);
+    }
+
+    for variant in Variant::into_enum_iter() {
+        if str.to_lowercase() == variant.to_string().to_lowercase() {
+            return Ok(variant);
+        }
+    }
+
+    bail!(
+        "Variant '{}' is not recognized, use one of: {}",
+        s,
+        get_all_as_vec_str().join(", ")
+    );
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("").unwrap(), Variant::Classic);
+    assert_eq!(parse(" ultimate ").unwrap(), Variant::Ultimate);
+}
+
+#[test]
+#[should_panic(
+    expected = "Variant 'chess' is not recognized, use one of: Classic, Ultimate, Qubic, Wild, Notakto, OrderChaos, Sliding"
+)]
+fn test_parse_wrong_string() {
+    let _v = parse("chess").unwrap();
+}
@@ -0,0 +1,39 @@
+// Board as a double vector, the representation used before bitboards.
+// Kept as a reference implementation for tests and benchmarks.
+
+use crate::types::board::CellGrid;
+use crate::types::board_dimension::BoardDimension;
+use crate::types::cell_value::CellValue;
+use crate::types::player_mark::PlayerMark;
+
+#[derive(Debug, Clone)]
+pub struct VecBoard {
+    pub dimension: BoardDimension,
+    cells: Vec<Vec<CellValue>>,
+}
+
+impl VecBoard {
+    pub fn new(dimension: BoardDimension) -> VecBoard {
+        let width = dimension.get_width() as usize;
+        let height = dimension.get_height() as usize;
+
+        VecBoard {
+            dimension,
+            cells: vec![vec![CellValue::new(None); width]; height],
+        }
+    }
+}
+
+impl CellGrid for VecBoard {
+    fn get_dimension(&self) -> &BoardDimension {
+        &self.dimension
+    }
+
+    fn get_cell(&self, row_idx: usize, col_idx: usize) -> Option<PlayerMark> {
+        self.cells[row_idx][col_idx].get_value().clone()
+    }
+
+    fn set_cell(&mut self, row_idx: usize, col_idx: usize, value: Option<PlayerMark>) {
+        self.cells[row_idx][col_idx].set_value(value);
+    }
+}
@@ -0,0 +1,81 @@
+// Marks in a row that win, like 4 in a row for Connect Four on 7x6.
+// u8 type with constraints. It can be created only through parse.
+
+use crate::types::board_dimension::BoardDimension;
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WinLength(u8);
+
+const MIN_VALUE: u8 = 3;
+const MAX_VALUE: u8 = 8;
+const DEFAULT: &str = "Shorter Side";
+
+impl WinLength {
+    pub fn get_value(&self) -> u8 {
+        self.0
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    let mut vec_str = vec![get_default_as_str()];
+    vec_str.extend((MIN_VALUE..=MAX_VALUE).map(|i| i.to_string()));
+    vec_str
+}
+
+pub fn get_default() -> Option<WinLength> {
+    None
+}
+
+pub fn get_default_as_str() -> String {
+    String::from(DEFAULT)
+}
+
+// None when a line has to be as long as the shorter side. The length is checked
+// against the board, so the dimension has to be known.
+pub fn parse(
+    board_dimension: &BoardDimension,
+    s: &str,
+) -> Result<Option<WinLength>, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() || str.to_lowercase() == DEFAULT.to_lowercase() {
+        return Ok(get_default());
+    }
+
+    let val = match str.parse::<u8>() {
+        Ok(val) => val,
+        Err(_) => bail!("Win Length '{}' is not a number", s),
+    };
+    if val < MIN_VALUE {
+        bail!("Win Length can not be less than {}", MIN_VALUE);
+    }
+    if val > board_dimension.get_line_length() {
+        bail!(
+            "Win Length can not be greater than the shorter side {}",
+            board_dimension.get_line_length()
+        );
+    }
+
+    Ok(Some(WinLength(val)))
+}
+
+#[cfg(test)]
+use crate::types::board_dimension::{parse as parse_board_dimension, parse_sides};
+
+#[test]
+fn test_parse() {
+    let board_dimension = parse_board_dimension("4").unwrap();
+    assert_eq!(parse(&board_dimension, " ").unwrap(), None);
+    assert_eq!(parse(&board_dimension, "shorter side").unwrap(), None);
+    assert_eq!(parse(&board_dimension, "3").unwrap(), Some(WinLength(3)));
+}
+
+#[test]
+#[should_panic(expected = "Win Length can not be greater than the shorter side 6")]
+fn test_parse_longer_than_shorter_side() {
+    let board_dimension =
+        parse_sides(&parse_board_dimension("3").unwrap(), Some("7"), Some("6")).unwrap();
+    let _wl = parse(&board_dimension, "7").unwrap();
+}
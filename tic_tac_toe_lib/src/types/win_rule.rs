@@ -0,0 +1,64 @@
+// How a game of more than two players is won. It can be created only through parse.
+
+use crate::types::errors::TicTacToeError;
+use anyhow::bail;
+use enum_iterator::IntoEnumIterator;
+use std::fmt;
+
+#[derive(Debug, IntoEnumIterator, PartialEq, Clone)]
+pub enum WinRule {
+    // The first player to complete a line wins
+    FirstToLine,
+    // Completing a line eliminates the player, the last one remaining wins
+    Elimination,
+}
+
+const DEFAULT_WIN_RULE: WinRule = WinRule::FirstToLine;
+
+impl fmt::Display for WinRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+pub fn get_all_as_vec_str() -> Vec<String> {
+    WinRule::into_enum_iter().map(|wr| wr.to_string()).collect()
+}
+
+pub fn get_default() -> WinRule {
+    DEFAULT_WIN_RULE
+}
+
+pub fn parse(s: &str) -> Result<WinRule, TicTacToeError> {
+    let str = s.trim();
+
+    if str.is_empty() {
+        return Ok(DEFAULT_WIN_RULE);
+    }
+
+    for win_rule in WinRule::into_enum_iter() {
+        if str.to_lowercase() == win_rule.to_string().to_lowercase() {
+            return Ok(win_rule);
+        }
+    }
+
+    bail!(
+        "Win Rule '{}' is not recognized, use one of: {}",
+        s,
+        get_all_as_vec_str().join(", ")
+    );
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("").unwrap(), WinRule::FirstToLine);
+    assert_eq!(parse(" elimination ").unwrap(), WinRule::Elimination);
+}
+
+#[test]
+#[should_panic(
+    expected = "Win Rule 'last' is not recognized, use one of: FirstToLine, Elimination"
+)]
+fn test_parse_wrong_string() {
+    let _wr = parse("last").unwrap();
+}
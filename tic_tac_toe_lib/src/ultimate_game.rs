@@ -0,0 +1,284 @@
+// NOTE 18: Public type for Ultimate tic-tac-toe. Every cell of the big board is a
+//          small board, a small board won by a player counts as that player's mark
+//          on the big board. The cell played sends the opponent to the small board
+//          with the same number, a decided board leaves the choice free.
+
+#[cfg(test)]
+use crate::game_state::deserialize_ultimate_game_state;
+use crate::game_state::{OutcomeState, UltimateGameState};
+use crate::settings::{parse, Settings};
+use crate::state_calculation::calculate_result_after_move;
+use crate::types::board::{Board, CellGrid};
+use crate::types::errors::TicTacToeError;
+use crate::types::outcome::{DrawReason, Outcome, WinReason};
+use crate::types::player_mark::PlayerMark;
+use crate::types::ultimate_move::parse as parse_ultimate_move;
+use crate::types::variant::Variant;
+use anyhow::bail;
+
+#[derive(Debug, Clone)]
+pub struct UltimateGame {
+    settings: Settings,
+    // Small boards in the order of their numbers
+    boards: Vec<Board>,
+    board_outcomes: Vec<Outcome>,
+    // Marks of the won small boards
+    big_board: Board,
+    // Index of the small board the player on move has to play on, None for free choice
+    active_board: Option<usize>,
+    next_on_move: PlayerMark,
+    outcome: Outcome,
+    moves: Vec<String>,
+}
+
+impl UltimateGame {
+    pub fn new(settings_str: &str) -> Result<UltimateGame, TicTacToeError> {
+        let settings = parse(settings_str)?;
+        if settings.variant != Variant::Ultimate {
+            bail!("Variant '{}' is not Ultimate", settings.variant);
+        }
+        if settings.early_draw
+            || settings.misere
+            || settings.gravity
+            || settings.time_control.is_some()
+            || !settings.board_dimension.is_square()
+            || settings.board_count.get_value() > 1
+            || settings.turn_order.get_players().len() > 2
+            || !settings.blocked_cells.is_empty()
+            || settings.wrap
+            || settings.swap
+            || settings.win_length.is_some()
+        {
+            bail!("Ultimate supports only First Player and Board Size settings");
+        }
+
+        let board_count = (settings.board_dimension.get_width() as usize).pow(2);
+        Ok(UltimateGame {
+            boards: vec![Board::new(settings.board_dimension.clone()); board_count],
+            board_outcomes: vec![Outcome::InProgress; board_count],
+            big_board: Board::new(settings.board_dimension.clone()),
+            active_board: None,
+            next_on_move: settings.first_player.clone(),
+            outcome: Outcome::InProgress,
+            moves: vec![],
+            settings,
+        })
+    }
+
+    pub fn play_move(&mut self, move_str: &str) -> Result<(), TicTacToeError> {
+        if self.outcome.is_finished() {
+            bail!("Game is finished, you can not play a move");
+        }
+
+        let ultimate_move = parse_ultimate_move(&self.settings.board_dimension, move_str)?;
+        let board_idx = ultimate_move.board.get_value() as usize - 1;
+        let (row_idx, col_idx) = ultimate_move.cell.get_indices();
+        let (row_idx, col_idx) = (row_idx as usize, col_idx as usize);
+
+        if let Some(active_board) = self.active_board {
+            if active_board != board_idx {
+                bail!("Move has to be played on board {}", active_board + 1);
+            }
+        }
+        if self.board_outcomes[board_idx].is_finished() {
+            bail!("Board {} is already decided", board_idx + 1);
+        }
+        if let Some(pm) = self.boards[board_idx].get_cell(row_idx, col_idx) {
+            bail!("Cell {} is already filled with '{}'", move_str.trim(), pm);
+        }
+
+        self.boards[board_idx].set_cell(row_idx, col_idx, Some(self.next_on_move.clone()));
+        self.moves.push(ultimate_move.to_string());
+
+        // small board first, a win there is a mark on the big board
+        let board_outcome =
+            calculate_result_after_move(&self.boards[board_idx], row_idx, col_idx, false);
+        if let Some(winner) = board_outcome.get_winner() {
+            let (big_row_idx, big_col_idx) = ultimate_move.board.get_indices();
+            let (big_row_idx, big_col_idx) = (big_row_idx as usize, big_col_idx as usize);
+            self.big_board
+                .set_cell(big_row_idx, big_col_idx, Some(winner.clone()));
+            if let Outcome::Win { winner, line, .. } =
+                calculate_result_after_move(&self.big_board, big_row_idx, big_col_idx, false)
+            {
+                self.outcome = Outcome::Win {
+                    winner,
+                    reason: WinReason::Line,
+                    line,
+                };
+            }
+        }
+        self.board_outcomes[board_idx] = board_outcome;
+
+        self.next_on_move = self.next_on_move.get_opponent();
+
+        let next_board_idx = ultimate_move.cell.get_value() as usize - 1;
+        self.active_board = if self.board_outcomes[next_board_idx].is_finished() {
+            None
+        } else {
+            Some(next_board_idx)
+        };
+
+        // drawn small boards count for nobody, the game is drawn when all are decided
+        if !self.outcome.is_finished() && self.board_outcomes.iter().all(|o| o.is_finished()) {
+            self.outcome = Outcome::Draw {
+                reason: DrawReason::BoardFull,
+            };
+        }
+
+        Ok(())
+    }
+
+    pub fn legal_moves(&self) -> Vec<String> {
+        if self.outcome.is_finished() {
+            return vec![];
+        }
+
+        let board_dim = self.settings.board_dimension.get_width() as usize;
+
+        let mut moves: Vec<String> = vec![];
+        for (board_idx, board) in self.boards.iter().enumerate() {
+            if self.board_outcomes[board_idx].is_finished()
+                || self.active_board.is_some_and(|active| active != board_idx)
+            {
+                continue;
+            }
+            for cell_idx in 0..board_dim * board_dim {
+                if board
+                    .get_cell(cell_idx / board_dim, cell_idx % board_dim)
+                    .is_none()
+                {
+                    moves.push(format!("{}-{}", board_idx + 1, cell_idx + 1));
+                }
+            }
+        }
+        moves
+    }
+
+    pub fn get_outcome(&self) -> &Outcome {
+        &self.outcome
+    }
+
+    pub fn get_state(&self) -> String {
+        let board_dim = self.settings.board_dimension.get_width() as usize;
+
+        let boards = self
+            .boards
+            .iter()
+            .map(|board| {
+                (0..board_dim)
+                    .map(|row_idx| {
+                        (0..board_dim)
+                            .map(|col_idx| match board.get_cell(row_idx, col_idx) {
+                                None => (row_idx * board_dim + col_idx + 1).to_string(),
+                                Some(pm) => pm.to_string(),
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let us = UltimateGameState {
+            boards,
+            board_outcomes: self.board_outcomes.iter().map(OutcomeState::from).collect(),
+            active_board: self.active_board.map(|board_idx| board_idx as u8 + 1),
+            next_on_move: self.next_on_move.to_string(),
+            finished: self.outcome.is_finished(),
+            outcome: OutcomeState::from(&self.outcome),
+            moves: self.moves.clone(),
+        };
+
+        serde_json::to_string(&us).unwrap()
+    }
+}
+
+#[test]
+fn test_new_state() {
+    let g = UltimateGame::new("O,3,variant=Ultimate").unwrap();
+    let state = deserialize_ultimate_game_state(&g.get_state());
+
+    assert_eq!(state.boards.len(), 9);
+    assert_eq!(state.boards[4][1], vec!["4", "5", "6"]);
+    assert_eq!(state.active_board, None);
+    assert_eq!(state.next_on_move, "O");
+    assert_eq!(g.legal_moves().len(), 81);
+}
+
+#[test]
+fn test_move_sends_opponent_to_board() {
+    let mut g = UltimateGame::new("X,3,variant=Ultimate").unwrap();
+    g.play_move("5-3").unwrap();
+
+    let state = deserialize_ultimate_game_state(&g.get_state());
+    assert_eq!(state.boards[4][0], vec!["1", "2", "X"]);
+    assert_eq!(state.active_board, Some(3));
+    assert_eq!(g.legal_moves()[0], "3-1");
+    assert_eq!(g.legal_moves().len(), 9);
+}
+
+#[test]
+#[should_panic(expected = "Move has to be played on board 3")]
+fn test_move_on_wrong_board() {
+    let mut g = UltimateGame::new("X,3,variant=Ultimate").unwrap();
+    g.play_move("5-3").unwrap();
+    g.play_move("4-1").unwrap();
+}
+
+#[test]
+fn test_won_board_frees_choice() {
+    let mut g = UltimateGame::new("X,3,variant=Ultimate").unwrap();
+    // O keeps sending X back to board 1, X completes its first row
+    for ultimate_move in ["5-5", "5-1", "1-2", "2-1", "1-3", "3-1", "1-1"] {
+        g.play_move(ultimate_move).unwrap();
+    }
+
+    let state = deserialize_ultimate_game_state(&g.get_state());
+    assert_eq!(state.board_outcomes[0].winner, Some(String::from("X")));
+    assert_eq!(state.board_outcomes[0].line, vec![1, 2, 3]);
+    // O was sent to the decided board 1, any other board can be played
+    assert_eq!(state.active_board, None);
+    assert_eq!(g.legal_moves().len(), 81 - 9 - 4);
+    assert!(!state.finished);
+}
+
+#[test]
+#[should_panic(expected = "Board 1 is already decided")]
+fn test_move_on_decided_board() {
+    let mut g = UltimateGame::new("X,3,variant=Ultimate").unwrap();
+    for ultimate_move in ["5-5", "5-1", "1-2", "2-1", "1-3", "3-1", "1-1"] {
+        g.play_move(ultimate_move).unwrap();
+    }
+    g.play_move("1-5").unwrap();
+}
+
+#[test]
+fn test_random_games_follow_big_board() {
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    for seed in 0..20 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut g = UltimateGame::new("X,3,variant=Ultimate").unwrap();
+        while let Some(ultimate_move) = g.legal_moves().choose(&mut rng) {
+            g.play_move(ultimate_move).unwrap();
+        }
+
+        // a won game has a line of small boards won by the winner
+        let state = deserialize_ultimate_game_state(&g.get_state());
+        assert!(state.finished);
+        if let Some(winner) = &state.outcome.winner {
+            assert_eq!(state.outcome.line.len(), 3);
+            for board_number in &state.outcome.line {
+                let board_outcome = &state.board_outcomes[*board_number as usize - 1];
+                assert_eq!(board_outcome.winner.as_ref(), Some(winner));
+            }
+        } else {
+            assert!(state
+                .board_outcomes
+                .iter()
+                .all(|o| o.status != "InProgress"));
+        }
+    }
+}